@@ -1,20 +1,45 @@
 use anchor_lang::prelude::*;
-use crate::state::content::{Chapter, Book};
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{Chapter, ChapterContent, ChapterStatus, Book, ReleaseSchedule};
+use crate::state::rating::RatingAggregate;
+use crate::state::user_account::WriterAccount;
 use crate::errors::PublishingPlatformError;
-use anchor_spl::token::Mint;
+use anchor_spl::{
+    token::Mint,
+    metadata::{Metadata, MetadataAccount},
+};
 #[derive(Accounts)]
 pub struct AddChapter<'info> {
     #[account(mut)]
     pub writer: Signer<'info>,
+    // Chapter authors need a writer account, unlock_chapter credits royalties to it
+    #[account(
+        seeds = [b"writer".as_ref(), writer.key().as_ref()],
+        bump = writer_account.bump,
+    )]
+    pub writer_account: Account<'info, WriterAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = chapter_mint.supply == 1 @ PublishingPlatformError::InvalidChapterSupply,
+    )]
     pub chapter_mint: Account<'info, Mint>,
+    #[account(
+        seeds = [
+            b"metadata",
+            metadata_program.key().as_ref(),
+            chapter_mint.key().as_ref()
+        ],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub chapter_metadata: Box<Account<'info, MetadataAccount>>,
     
     #[account(
         mut,
         seeds = [b"book", book_collection.key().as_ref()],
         bump,
-        constraint = book.author == writer.key() @ PublishingPlatformError::UnauthorizedWriter
+        constraint = book.can_write(&writer.key()) @ PublishingPlatformError::UnauthorizedWriter
     )]
     pub book: Account<'info, Book>,
 
@@ -29,11 +54,52 @@ pub struct AddChapter<'info> {
     )]
     pub chapter: Account<'info, Chapter>,
     
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    pub metadata_program: Program<'info, Metadata>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> AddChapter<'info> {
-    pub fn add_chapter(&mut self, title: String, content_uri: String) -> Result<()> {
+    pub fn add_chapter(
+        &mut self,
+        title: String,
+        content: ChapterContent,
+        content_hash: [u8; 32],
+        price_lamports: Option<u64>,
+        schedule: Option<ReleaseSchedule>,
+    ) -> Result<()> {
+        require!(price_lamports != Some(0), PublishingPlatformError::InvalidChapterPrice);
+        let (content_uri, uri_commitment) = match content {
+            ChapterContent::Public { content_uri } => {
+                Chapter::validate_content_uri(&content_uri)?;
+                (content_uri, None)
+            }
+            ChapterContent::Committed { uri_commitment } => (String::new(), Some(uri_commitment)),
+        };
+
+        // Without a schedule the chapter is public right away
+        let now = Clock::get()?.unix_timestamp;
+        let schedule = schedule.unwrap_or(ReleaseSchedule {
+            early_access_at: now,
+            release_at: now,
+        });
+        schedule.validate()?;
+
+        let collection = self.chapter_metadata.collection
+            .as_ref()
+            .ok_or(PublishingPlatformError::ChapterNotInCollection)?;
+        require_keys_eq!(
+            collection.key,
+            self.book.collection_mint,
+            PublishingPlatformError::ChapterNotInCollection
+        );
+        require!(collection.verified, PublishingPlatformError::CollectionNotVerified);
+
         // Increment chapter count in book
         self.book.chapter_count = self.book.chapter_count.checked_add(1)
             .ok_or(PublishingPlatformError::ChapterLimitExceeded)?;
@@ -47,7 +113,18 @@ impl<'info> AddChapter<'info> {
             chapter_number: self.book.chapter_count,
             is_exclusive: false,
             review_count: 0,
-            rating: 0,
+            rating: RatingAggregate::default(),
+            price_lamports,
+            content_hash,
+            revision: 0,
+            early_access_at: schedule.early_access_at,
+            release_at: schedule.release_at,
+            uri_commitment,
+            revealed_at: None,
+            // Chapters of books with editors wait for approval
+            status: if self.book.editors.is_empty() { ChapterStatus::Published } else { ChapterStatus::Draft },
+            reviewed_by: None,
+            editorial_note: String::new(),
         });
         Ok(())
     }
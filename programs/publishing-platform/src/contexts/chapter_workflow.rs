@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{Book, Chapter, ChapterStatus, MAX_EDITORIAL_NOTE_LEN};
+use crate::errors::PublishingPlatformError;
+
+#[derive(Accounts)]
+pub struct ChapterWorkflow<'info> {
+    pub writer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"chapter", chapter.chapter_mint.as_ref()],
+        bump,
+        constraint = chapter.author == writer.key() @ PublishingPlatformError::UnauthorizedWriter
+    )]
+    pub chapter: Account<'info, Chapter>,
+    #[account(
+        seeds = [b"book", chapter.book_collection.as_ref()],
+        bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> ChapterWorkflow<'info> {
+    pub fn submit_for_review(&mut self) -> Result<()> {
+        require!(!self.book.editors.is_empty(), PublishingPlatformError::InvalidChapterStatus);
+
+        // Rejected chapters can be resubmitted once reworked
+        self.chapter.transition(
+            &[ChapterStatus::Draft, ChapterStatus::Rejected],
+            ChapterStatus::Submitted,
+        )
+    }
+
+    // Pulls a chapter out of review, e.g. when the book no longer has editors to rule on it
+    pub fn withdraw_chapter(&mut self) -> Result<()> {
+        self.chapter.transition(
+            &[ChapterStatus::Submitted, ChapterStatus::Rejected],
+            ChapterStatus::Draft,
+        )
+    }
+
+    pub fn publish_chapter(&mut self) -> Result<()> {
+        if self.book.editors.is_empty() {
+            self.chapter.transition(
+                &[ChapterStatus::Draft, ChapterStatus::Approved],
+                ChapterStatus::Published,
+            )
+        } else {
+            self.chapter.transition(&[ChapterStatus::Approved], ChapterStatus::Published)
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct EditorDecision<'info> {
+    pub editor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"chapter", chapter.chapter_mint.as_ref()],
+        bump
+    )]
+    pub chapter: Account<'info, Chapter>,
+    #[account(
+        seeds = [b"book", chapter.book_collection.as_ref()],
+        bump,
+        constraint = book.editors.contains(&editor.key()) @ PublishingPlatformError::NotAnEditor
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> EditorDecision<'info> {
+    pub fn approve_chapter(&mut self, note: String) -> Result<()> {
+        self.decide(ChapterStatus::Approved, note)
+    }
+
+    pub fn reject_chapter(&mut self, note: String) -> Result<()> {
+        self.decide(ChapterStatus::Rejected, note)
+    }
+
+    fn decide(&mut self, status: ChapterStatus, note: String) -> Result<()> {
+        require!(note.len() <= MAX_EDITORIAL_NOTE_LEN, PublishingPlatformError::EditorialNoteTooLong);
+        require_keys_neq!(
+            self.editor.key(),
+            self.chapter.author,
+            PublishingPlatformError::EditorCannotWrite
+        );
+
+        self.chapter.transition(&[ChapterStatus::Submitted], status)?;
+        self.chapter.reviewed_by = Some(self.editor.key());
+        self.chapter.editorial_note = note;
+        Ok(())
+    }
+}
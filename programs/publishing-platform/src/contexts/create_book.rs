@@ -1,13 +1,39 @@
 use anchor_lang::prelude::*;
-use crate::state::content::Book;
-use anchor_spl::token::Mint;
+use crate::errors::PublishingPlatformError;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{Book, BookStatus, Contributor, ContributorRole, TOTAL_SHARE_BPS};
+use crate::state::user_account::WriterAccount;
+use crate::state::rating::RatingAggregate;
+use anchor_spl::{
+    token::{Mint, TokenAccount},
+    metadata::{Metadata, MetadataAccount},
+};
 
 #[derive(Accounts)]
 pub struct CreateBook<'info> {
     #[account(mut)]
     pub writer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"writer".as_ref(), writer.key().as_ref()],
+        bump = writer_account.bump,
+    )]
+    pub writer_account: Account<'info, WriterAccount>,
     #[account(mut)]
     pub collection_mint: Account<'info, Mint>,
+    #[account(
+        seeds = [
+            b"metadata",
+            metadata_program.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        seeds::program = metadata_program.key(),
+        bump,
+        constraint = collection_metadata.collection_details.is_some() @ PublishingPlatformError::NotACollection,
+    )]
+    pub collection_metadata: Box<Account<'info, MetadataAccount>>,
+    // Only required when the writer is not a verified creator of the collection
+    pub collection_ata: Option<Account<'info, TokenAccount>>,
     #[account(
         init,
         payer = writer,
@@ -16,21 +42,61 @@ pub struct CreateBook<'info> {
         bump
     )]
     pub book: Account<'info, Book>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    pub metadata_program: Program<'info, Metadata>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> CreateBook<'info> {
     pub fn create_book(&mut self, title: String, royalties: u8, genre: String) -> Result<()> {
+        Book::validate_details(&title, &genre, royalties)?;
+        let writer = self.writer.key();
+
+        let is_verified_creator = self.collection_metadata.creators
+            .as_ref()
+            .is_some_and(|creators| creators.iter().any(|c| c.address == writer && c.verified));
+
+        let is_holder = self.collection_ata
+            .as_ref()
+            .is_some_and(|ata| {
+                ata.owner == writer && ata.mint == self.collection_mint.key() && ata.amount > 0
+            });
+
+        require!(
+            is_verified_creator || is_holder,
+            PublishingPlatformError::NotCollectionOwner
+        );
+
         self.book.set_inner(Book {
             title,
-            author: self.writer.key(),
+            author: writer,
             collection_mint: self.collection_mint.key(),
             chapter_count: 0,
             genre,
             royalty_percentage: royalties,
             total_sales: 0,
-            review_score: 0,
+            chapter_rating: RatingAggregate::default(),
+            book_rating: RatingAggregate::default(),
+            status: BookStatus::Draft,
+            exclusive_content_count: 0,
+            live_exclusive_content: 0,
+            total_tips: 0,
+            contributors: vec![Contributor {
+                wallet: writer,
+                role: ContributorRole::Author,
+                share_bps: TOTAL_SHARE_BPS,
+                can_write: true,
+            }],
+            editors: Vec::new(),
         });
+
+        self.writer_account.book_count = self.writer_account.book_count.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::content::ExclusiveContent;
+use crate::errors::PublishingPlatformError;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{Book, ExclusiveContent};
+use crate::state::access_policy::AccessPolicy;
 use anchor_spl::token::Mint;
 
 #[derive(Accounts)]
@@ -7,29 +10,67 @@ pub struct CreateExclusiveContent<'info> {
     #[account(mut)]
     pub writer: Signer<'info>,
     pub collection_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"book", collection_mint.key().as_ref()],
+        bump,
+        constraint = book.author == writer.key() @ PublishingPlatformError::UnauthorizedWriter
+    )]
+    pub book: Account<'info, Book>,
     #[account(
         init,
         payer = writer,
         space = 8 + ExclusiveContent::INIT_SPACE,
         seeds = [
             b"exclusive",
-            collection_mint.key().as_ref()
+            collection_mint.key().as_ref(),
+            book.exclusive_content_count.to_le_bytes().as_ref()
         ],
         bump
     )]
     pub exclusive_content: Account<'info, ExclusiveContent>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> CreateExclusiveContent<'info> {
-    pub fn create_exclusive_content(&mut self, content_uri: String) -> Result<()> {
+    pub fn create_exclusive_content(
+        &mut self,
+        content_uri: String,
+        available_until: Option<i64>,
+        policy: Option<AccessPolicy>,
+        bumps: &CreateExclusiveContentBumps,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        if let Some(available_until) = available_until {
+            require!(available_until > now, PublishingPlatformError::InvalidAvailability);
+        }
+
+        // Without an explicit policy, holding any chapter of the collection grants access
+        let policy = policy.unwrap_or_else(|| AccessPolicy::holds_collection(self.collection_mint.key()));
+        policy.validate()?;
+
         self.exclusive_content.set_inner(ExclusiveContent {
             author: self.writer.key(),
             required_collection: self.collection_mint.key(),
             content_uri,
             is_active: true,
-            created_at: Clock::get()?.unix_timestamp,
+            created_at: now,
+            index: self.book.exclusive_content_count,
+            available_until,
+            policy,
+            bump: bumps.exclusive_content,
         });
+
+        self.book.exclusive_content_count = self.book.exclusive_content_count.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.book.live_exclusive_content = self.book.live_exclusive_content.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::errors::PublishingPlatformError;
+use crate::state::platform_account::PlatformAccount;
 use crate::state::user_account::ReaderAccount;
 
 #[derive(Accounts)]
@@ -14,6 +16,12 @@ pub struct CreateReaderAccount<'info> {
         space = 8 + ReaderAccount::INIT_SPACE,
     )]
     pub user_account: Account<'info, ReaderAccount>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
     pub system_program: Program<'info, System>,
 }
 
@@ -26,6 +34,9 @@ impl<'info> CreateReaderAccount<'info> {
             review_count: 0,
             total_upvotes: 0,
             reputation_score: 0,
+            reputation_updated_at: Clock::get()?.unix_timestamp,
+            penalty_count: 0,
+            reviews_submitted: 0,
         });
         Ok(())
     }
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::errors::PublishingPlatformError;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::user_account::WriterAccount;
+use crate::state::subscription::{SubscriptionPlan, MAX_PLAN_BOOKS};
+
+#[derive(Accounts)]
+pub struct CreateSubscriptionPlan<'info> {
+    #[account(mut)]
+    pub writer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"writer".as_ref(), writer.key().as_ref()],
+        bump = writer_account.bump,
+    )]
+    pub writer_account: Account<'info, WriterAccount>,
+    #[account(
+        init,
+        payer = writer,
+        space = 8 + SubscriptionPlan::INIT_SPACE,
+        seeds = [
+            b"plan",
+            writer.key().as_ref(),
+            writer_account.plan_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub subscription_plan: Account<'info, SubscriptionPlan>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateSubscriptionPlan<'info> {
+    pub fn create_subscription_plan(
+        &mut self,
+        price_per_period: u64,
+        period_seconds: i64,
+        books: Vec<Pubkey>,
+        bumps: &CreateSubscriptionPlanBumps,
+    ) -> Result<()> {
+        require!(price_per_period > 0, PublishingPlatformError::InvalidSubscriptionPrice);
+        require!(period_seconds > 0, PublishingPlatformError::InvalidSubscriptionPeriod);
+        require!(
+            !books.is_empty() && books.len() <= MAX_PLAN_BOOKS,
+            PublishingPlatformError::InvalidPlanBooks
+        );
+
+        // Covered books are only honoured for content authored by the plan's writer,
+        // so listing someone else's collection here grants nothing
+        self.subscription_plan.set_inner(SubscriptionPlan {
+            writer: self.writer.key(),
+            index: self.writer_account.plan_count,
+            price_per_period,
+            period_seconds,
+            books,
+            bump: bumps.subscription_plan,
+        });
+
+        self.writer_account.plan_count = self.writer_account.plan_count.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
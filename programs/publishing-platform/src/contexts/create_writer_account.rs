@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::errors::PublishingPlatformError;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::rating::RatingAggregate;
 use crate::state::user_account::WriterAccount;
 
 #[derive(Accounts)]
@@ -14,6 +17,12 @@ pub struct CreateWriterAccount<'info> {
         space = 8 + WriterAccount::INIT_SPACE,
     )]
     pub user_account: Account<'info, WriterAccount>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
     pub system_program: Program<'info, System>,
 }
 
@@ -25,6 +34,10 @@ impl<'info> CreateWriterAccount<'info> {
             bump: bumps.user_account,
             book_count: 0,
             total_royalties: 0,
+            plan_count: 0,
+            total_tips_received: 0,
+            tip_count: 0,
+            rating: RatingAggregate::default(),
         });
         Ok(())
     }
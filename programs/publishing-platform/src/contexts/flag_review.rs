@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::errors::PublishingPlatformError;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::review::{Review, ReviewFlag};
+use crate::state::user_account::ReaderAccount;
+
+#[derive(Accounts)]
+pub struct FlagReview<'info> {
+    #[account(mut)]
+    pub flagger: Signer<'info>,
+    // Only registered readers can flag
+    #[account(
+        seeds = [b"reader", flagger.key().as_ref()],
+        bump = flagger_account.bump,
+    )]
+    pub flagger_account: Account<'info, ReaderAccount>,
+    #[account(
+        mut,
+        seeds = [b"review", review.reviewer.as_ref(), review.target.as_ref()],
+        bump = review.bump,
+    )]
+    pub review: Account<'info, Review>,
+    #[account(
+        init,
+        payer = flagger,
+        space = 8 + ReviewFlag::INIT_SPACE,
+        seeds = [b"flag", flagger.key().as_ref(), review.key().as_ref(), review.nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub review_flag: Account<'info, ReviewFlag>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FlagReview<'info> {
+    pub fn flag_review(&mut self, bumps: &FlagReviewBumps) -> Result<()> {
+        self.review_flag.set_inner(ReviewFlag {
+            flagger: self.flagger.key(),
+            review: self.review.key(),
+            review_nonce: self.review.nonce,
+            created_at: Clock::get()?.unix_timestamp,
+            bump: bumps.review_flag,
+        });
+
+        self.review.flag_count = self.review.flag_count.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UnflagReview<'info> {
+    #[account(mut)]
+    pub flagger: Signer<'info>,
+    /// CHECK: the flagged review may since have been deleted or submitted again,
+    /// it is only loaded when it still carries the flag's nonce
+    #[account(mut, address = review_flag.review)]
+    pub review: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = flagger,
+        seeds = [b"flag", flagger.key().as_ref(), review_flag.review.as_ref(), review_flag.review_nonce.to_le_bytes().as_ref()],
+        bump = review_flag.bump,
+    )]
+    pub review_flag: Account<'info, ReviewFlag>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> UnflagReview<'info> {
+    pub fn unflag_review(&mut self) -> Result<()> {
+        // A flag on a review that is gone only gives its rent back
+        if self.review.owner != &crate::ID || self.review.data_is_empty() {
+            return Ok(());
+        }
+        let mut review = Review::try_deserialize(&mut &self.review.try_borrow_data()?[..])?;
+        if review.nonce != self.review_flag.review_nonce {
+            return Ok(());
+        }
+
+        review.flag_count = review.flag_count.saturating_sub(1);
+        review.try_serialize(&mut &mut self.review.try_borrow_mut_data()?[..])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{ExclusiveContent, Chapter};
+use crate::state::access_pass::{AccessPass, ACCESS_PASS_VALIDITY_SECONDS};
+use crate::state::subscription::{Subscription, SubscriptionPlan};
+use crate::state::chapter_unlock::ChapterUnlock;
+use crate::contexts::verify_content_access::{check_access, AccessProof};
+use crate::errors::PublishingPlatformError;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+pub struct GrantAccessPass<'info> {
+    #[account(mut)]
+    pub reader: Signer<'info>,
+    pub exclusive_content: Account<'info, ExclusiveContent>,
+    pub chapter: Option<Account<'info, Chapter>>,
+    pub chapter_ata: Option<Account<'info, TokenAccount>>,
+    pub chapter_unlock: Option<Account<'info, ChapterUnlock>>,
+    pub subscription: Option<Account<'info, Subscription>>,
+    pub subscription_plan: Option<Account<'info, SubscriptionPlan>>,
+    #[account(
+        init_if_needed,
+        payer = reader,
+        space = 8 + AccessPass::INIT_SPACE,
+        seeds = [
+            b"access",
+            reader.key().as_ref(),
+            exclusive_content.key().as_ref()
+        ],
+        bump
+    )]
+    pub access_pass: Account<'info, AccessPass>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> GrantAccessPass<'info> {
+    pub fn grant_access_pass(&mut self, remaining_accounts: &[AccountInfo], bumps: &GrantAccessPassBumps) -> Result<()> {
+        check_access(
+            &self.reader.key(),
+            &self.exclusive_content,
+            AccessProof {
+                chapter: self.chapter.as_deref(),
+                chapter_ata: self.chapter_ata.as_deref(),
+                chapter_unlock: self.chapter_unlock.as_deref(),
+                subscription: self.subscription.as_deref(),
+                subscription_plan: self.subscription_plan.as_ref(),
+            },
+            remaining_accounts,
+        )?;
+
+        // Granting again simply refreshes the expiry, which never outlasts
+        // the content's availability or the subscription that proved access
+        let now = Clock::get()?.unix_timestamp;
+        let mut expires_at = now.checked_add(ACCESS_PASS_VALIDITY_SECONDS)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        if let Some(available_until) = self.exclusive_content.available_until {
+            expires_at = expires_at.min(available_until);
+        }
+        if let Some(subscription) = &self.subscription {
+            expires_at = expires_at.min(subscription.expires_at);
+        }
+
+        self.access_pass.set_inner(AccessPass {
+            reader: self.reader.key(),
+            exclusive_content: self.exclusive_content.key(),
+            granted_at: now,
+            expires_at,
+            bump: bumps.access_pass,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CheckAccessPass<'info> {
+    #[account(
+        seeds = [
+            b"access",
+            access_pass.reader.as_ref(),
+            exclusive_content.key().as_ref()
+        ],
+        bump = access_pass.bump,
+    )]
+    pub access_pass: Account<'info, AccessPass>,
+    // Fails to load once the content is closed, taking its passes with it
+    pub exclusive_content: Account<'info, ExclusiveContent>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> CheckAccessPass<'info> {
+    pub fn check_access_pass(&self) -> Result<String> {
+        self.access_pass.ensure_valid(&self.exclusive_content, Clock::get()?.unix_timestamp)?;
+
+        Ok(self.exclusive_content.content_uri.clone())
+    }
+}
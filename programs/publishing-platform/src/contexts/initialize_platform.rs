@@ -1,26 +1,60 @@
-use anchor_lang::prelude::*;
-use crate::state::platform_account::PlatformAccount;
+use anchor_lang::{prelude::*, system_program::{Transfer, transfer}};
+use crate::state::platform_account::{PlatformAccount, MAX_FEE_BPS};
+use crate::state::reputation::ReputationConfig;
+use crate::errors::PublishingPlatformError;
 
 #[derive(Accounts)]
-//#[instruction(role: u8)]
 pub struct InitializePlatform<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     #[account(
         init,
         payer = user,
+        seeds = [b"platform"],
+        bump,
         space = 8 + PlatformAccount::INIT_SPACE,            
     )]
     pub platform_account: Account<'info, PlatformAccount>,
+    // Funded with the rent-exempt minimum here, so small fees can land in it
+    #[account(
+        mut,
+        seeds = [b"treasury", platform_account.key().as_ref()],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> InitializePlatform <'info> {
 
-    pub fn initialize_platform(&mut self) -> Result<()> {
+    pub fn initialize_platform(&mut self, fee_bps: u16, bumps: &InitializePlatformBumps) -> Result<()> {
+        require!(fee_bps <= MAX_FEE_BPS, PublishingPlatformError::InvalidFee);
+
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+        let missing = rent_exempt_minimum.saturating_sub(self.treasury.lamports());
+        if missing > 0 {
+            let cpi_ctx = CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.user.to_account_info(),
+                    to: self.treasury.to_account_info(),
+                },
+            );
+            transfer(cpi_ctx, missing)?;
+        }
+
         self.platform_account.set_inner(PlatformAccount { 
-            counter: 0,
+            admin: self.user.key(),
+            pending_admin: None,
+            fee_bps,
+            treasury: self.treasury.key(),
+            paused: false,
+            bump: bumps.platform_account,
+            treasury_bump: bumps.treasury,
+            allowed_tip_mints: Vec::new(),
+            reputation: ReputationConfig::DEFAULT,
+            moderators: Vec::new(),
         });
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::errors::PublishingPlatformError;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{Book, ExclusiveContent};
+use crate::state::access_policy::AccessPolicy;
+
+#[derive(Accounts)]
+pub struct ManageExclusiveContent<'info> {
+    pub writer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"exclusive",
+            exclusive_content.required_collection.as_ref(),
+            exclusive_content.index.to_le_bytes().as_ref()
+        ],
+        bump = exclusive_content.bump,
+        constraint = exclusive_content.author == writer.key() @ PublishingPlatformError::UnauthorizedWriter
+    )]
+    pub exclusive_content: Account<'info, ExclusiveContent>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> ManageExclusiveContent<'info> {
+    pub fn update_exclusive_content(&mut self, content_uri: String, available_until: Option<i64>) -> Result<()> {
+        if let Some(available_until) = available_until {
+            require!(
+                available_until > Clock::get()?.unix_timestamp,
+                PublishingPlatformError::InvalidAvailability
+            );
+        }
+
+        self.exclusive_content.content_uri = content_uri;
+        self.exclusive_content.available_until = available_until;
+        Ok(())
+    }
+
+    pub fn set_access_policy(&mut self, policy: AccessPolicy) -> Result<()> {
+        policy.validate()?;
+
+        self.exclusive_content.policy = policy;
+        Ok(())
+    }
+
+    pub fn deactivate_exclusive_content(&mut self) -> Result<()> {
+        self.exclusive_content.is_active = false;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CloseExclusiveContent<'info> {
+    #[account(mut)]
+    pub writer: Signer<'info>,
+    #[account(
+        mut,
+        close = writer,
+        seeds = [
+            b"exclusive",
+            exclusive_content.required_collection.as_ref(),
+            exclusive_content.index.to_le_bytes().as_ref()
+        ],
+        bump = exclusive_content.bump,
+        constraint = exclusive_content.author == writer.key() @ PublishingPlatformError::UnauthorizedWriter
+    )]
+    pub exclusive_content: Account<'info, ExclusiveContent>,
+    #[account(
+        mut,
+        seeds = [b"book", exclusive_content.required_collection.as_ref()],
+        bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> CloseExclusiveContent<'info> {
+    pub fn close_exclusive_content(&mut self) -> Result<()> {
+        self.book.live_exclusive_content = self.book.live_exclusive_content.saturating_sub(1);
+        Ok(())
+    }
+}
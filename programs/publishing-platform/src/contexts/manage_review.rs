@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use crate::errors::PublishingPlatformError;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{Book, Chapter};
+use crate::state::review::{RatingTargets, Review};
+use crate::state::user_account::{ReaderAccount, WriterAccount};
+
+#[derive(Accounts)]
+pub struct EditReview<'info> {
+    pub reviewer: Signer<'info>,
+    // Only passed for chapter reviews
+    #[account(mut, address = review.target)]
+    pub chapter: Option<Account<'info, Chapter>>,
+    // Only passed for chapter and book reviews
+    #[account(
+        mut,
+        seeds = [b"book", review.book_collection.as_ref()],
+        bump
+    )]
+    pub book: Option<Account<'info, Book>>,
+    // Only passed for writer reviews
+    #[account(mut, address = review.target)]
+    pub writer_account: Option<Account<'info, WriterAccount>>,
+    #[account(
+        mut,
+        seeds = [b"review", reviewer.key().as_ref(), review.target.as_ref()],
+        bump = review.bump,
+        constraint = !review.hidden @ PublishingPlatformError::ReviewHidden,
+    )]
+    pub review: Account<'info, Review>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> EditReview<'info> {
+    pub fn edit_review(&mut self, content: String, rating: u8) -> Result<()> {
+        Review::validate(&content, rating)?;
+
+        // Swap the old rating for the new one in the aggregates, the review keeps
+        // the weight it was submitted with
+        self.review.withdraw_rating(RatingTargets {
+            chapter: self.chapter.as_deref_mut(),
+            book: self.book.as_deref_mut(),
+            writer: self.writer_account.as_deref_mut(),
+        })?;
+        self.review.rating = rating;
+        self.review.add_rating(RatingTargets {
+            chapter: self.chapter.as_deref_mut(),
+            book: self.book.as_deref_mut(),
+            writer: self.writer_account.as_deref_mut(),
+        })?;
+
+        self.review.content = content;
+        self.review.updated_at = Some(Clock::get()?.unix_timestamp);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct DeleteReview<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"reader", reviewer.key().as_ref()],
+        bump = reader_account.bump,
+    )]
+    pub reader_account: Account<'info, ReaderAccount>,
+    // Only passed for chapter reviews
+    #[account(mut, address = review.target)]
+    pub chapter: Option<Account<'info, Chapter>>,
+    // Only passed for chapter and book reviews
+    #[account(
+        mut,
+        seeds = [b"book", review.book_collection.as_ref()],
+        bump
+    )]
+    pub book: Option<Account<'info, Book>>,
+    // Only passed for writer reviews
+    #[account(mut, address = review.target)]
+    pub writer_account: Option<Account<'info, WriterAccount>>,
+    #[account(
+        mut,
+        close = reviewer,
+        seeds = [b"review", reviewer.key().as_ref(), review.target.as_ref()],
+        bump = review.bump,
+        // Hidden reviews can only be closed by a moderator through remove_review,
+        // otherwise deleting and resubmitting would undo the hide
+        constraint = !review.hidden @ PublishingPlatformError::ReviewHidden,
+    )]
+    pub review: Account<'info, Review>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> DeleteReview<'info> {
+    pub fn delete_review(&mut self) -> Result<()> {
+        if let Some(chapter) = self.chapter.as_deref_mut() {
+            chapter.review_count = chapter.review_count.saturating_sub(1);
+        }
+        self.review.withdraw_rating(RatingTargets {
+            chapter: self.chapter.as_deref_mut(),
+            book: self.book.as_deref_mut(),
+            writer: self.writer_account.as_deref_mut(),
+        })?;
+
+        self.reader_account.review_count = self.reader_account.review_count.saturating_sub(1);
+        // Upvotes on a deleted review no longer count towards the reviewer
+        self.reader_account.total_upvotes = self.reader_account.total_upvotes
+            .saturating_sub(self.review.upvotes);
+
+        // Take back what the review earned, decay means this may leave the score lower than before
+        let reputation = &self.platform_account.reputation;
+        let earned = (self.review.upvotes as u64)
+            .saturating_mul(reputation.upvote_weight as u64)
+            .saturating_add(reputation.review_reward(self.review.verified_holder));
+        self.reader_account.penalize(earned, reputation, Clock::get()?.unix_timestamp)?;
+        Ok(())
+    }
+}
@@ -1,18 +1,58 @@
 pub mod initialize_platform;
+pub mod platform_admin;
 pub mod create_writer_account;  
 pub mod create_reader_account;
 pub mod tip_writer;
+pub mod tip_writer_token;
 pub mod create_book;
+pub mod update_book;
 pub mod add_chapter;
+pub mod update_chapter;
+pub mod unlock_chapter;
+pub mod read_chapter;
+pub mod reveal_chapter;
+pub mod chapter_workflow;
 pub mod create_exclusive_content;
+pub mod manage_exclusive_content;
 pub mod verify_content_access;
+pub mod grant_access_pass;
 pub mod submit_review;
+pub mod submit_book_review;
+pub mod submit_writer_review;
+pub mod manage_review;
+pub mod upvote_review;
+pub mod flag_review;
+pub mod moderate_review;
+pub mod review_reply;
+pub mod recompute_reputation;
+pub mod create_subscription_plan;
+pub mod subscribe;
 pub use initialize_platform::*;
+pub use platform_admin::*;
 pub use create_writer_account::*;
 pub use create_reader_account::*;
 pub use tip_writer::*;
+pub use tip_writer_token::*;
 pub use create_book::*;
+pub use update_book::*;
 pub use add_chapter::*;
+pub use update_chapter::*;
+pub use unlock_chapter::*;
+pub use read_chapter::*;
+pub use reveal_chapter::*;
+pub use chapter_workflow::*;
 pub use create_exclusive_content::*;
+pub use manage_exclusive_content::*;
 pub use verify_content_access::*;
-pub use submit_review::*;
\ No newline at end of file
+pub use grant_access_pass::*;
+pub use submit_review::*;
+pub use submit_book_review::*;
+pub use submit_writer_review::*;
+pub use manage_review::*;
+pub use upvote_review::*;
+pub use flag_review::*;
+pub use moderate_review::*;
+pub use review_reply::*;
+pub use recompute_reputation::*;
+pub use create_subscription_plan::*;
+pub use subscribe::*;
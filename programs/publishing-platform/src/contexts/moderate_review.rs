@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use crate::errors::PublishingPlatformError;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{Book, Chapter};
+use crate::state::review::{RatingTargets, Review};
+use crate::state::user_account::{ReaderAccount, WriterAccount};
+
+#[derive(Accounts)]
+pub struct HideReview<'info> {
+    pub moderator: Signer<'info>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = platform_account.is_moderator(&moderator.key()) @ PublishingPlatformError::NotAModerator,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    #[account(
+        mut,
+        seeds = [b"review", review.reviewer.as_ref(), review.target.as_ref()],
+        bump = review.bump,
+        constraint = !review.hidden @ PublishingPlatformError::ReviewHidden,
+    )]
+    pub review: Account<'info, Review>,
+    #[account(
+        mut,
+        seeds = [b"reader", review.reviewer.as_ref()],
+        bump = reviewer_account.bump,
+    )]
+    pub reviewer_account: Account<'info, ReaderAccount>,
+    // Only passed for chapter reviews
+    #[account(mut, address = review.target)]
+    pub chapter: Option<Account<'info, Chapter>>,
+    // Only passed for chapter and book reviews
+    #[account(
+        mut,
+        seeds = [b"book", review.book_collection.as_ref()],
+        bump
+    )]
+    pub book: Option<Account<'info, Book>>,
+    // Only passed for writer reviews
+    #[account(mut, address = review.target)]
+    pub writer_account: Option<Account<'info, WriterAccount>>,
+}
+
+impl<'info> HideReview<'info> {
+    pub fn hide_review(&mut self) -> Result<()> {
+        self.review.withdraw_rating(RatingTargets {
+            chapter: self.chapter.as_deref_mut(),
+            book: self.book.as_deref_mut(),
+            writer: self.writer_account.as_deref_mut(),
+        })?;
+        self.review.hidden = true;
+
+        penalize_reviewer(&mut self.reviewer_account, &self.platform_account)
+    }
+}
+
+#[derive(Accounts)]
+pub struct RemoveReview<'info> {
+    pub moderator: Signer<'info>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = platform_account.is_moderator(&moderator.key()) @ PublishingPlatformError::NotAModerator,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    // Rent goes back to the reviewer, not the moderator
+    #[account(mut, address = review.reviewer)]
+    pub reviewer: SystemAccount<'info>,
+    #[account(
+        mut,
+        close = reviewer,
+        seeds = [b"review", review.reviewer.as_ref(), review.target.as_ref()],
+        bump = review.bump,
+    )]
+    pub review: Account<'info, Review>,
+    #[account(
+        mut,
+        seeds = [b"reader", review.reviewer.as_ref()],
+        bump = reviewer_account.bump,
+    )]
+    pub reviewer_account: Account<'info, ReaderAccount>,
+    // Only passed for chapter reviews
+    #[account(mut, address = review.target)]
+    pub chapter: Option<Account<'info, Chapter>>,
+    // Only passed for chapter and book reviews
+    #[account(
+        mut,
+        seeds = [b"book", review.book_collection.as_ref()],
+        bump
+    )]
+    pub book: Option<Account<'info, Book>>,
+    // Only passed for writer reviews
+    #[account(mut, address = review.target)]
+    pub writer_account: Option<Account<'info, WriterAccount>>,
+}
+
+impl<'info> RemoveReview<'info> {
+    pub fn remove_review(&mut self) -> Result<()> {
+        // A hidden review was already withdrawn and penalized when it was hidden
+        if !self.review.hidden {
+            self.review.withdraw_rating(RatingTargets {
+                chapter: self.chapter.as_deref_mut(),
+                book: self.book.as_deref_mut(),
+                writer: self.writer_account.as_deref_mut(),
+            })?;
+            penalize_reviewer(&mut self.reviewer_account, &self.platform_account)?;
+        }
+
+        if let Some(chapter) = self.chapter.as_deref_mut() {
+            chapter.review_count = chapter.review_count.saturating_sub(1);
+        }
+        self.reviewer_account.review_count = self.reviewer_account.review_count.saturating_sub(1);
+        self.reviewer_account.total_upvotes = self.reviewer_account.total_upvotes
+            .saturating_sub(self.review.upvotes);
+        Ok(())
+    }
+}
+
+fn penalize_reviewer(reviewer_account: &mut ReaderAccount, platform_account: &PlatformAccount) -> Result<()> {
+    reviewer_account.penalty_count = reviewer_account.penalty_count.checked_add(1)
+        .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+
+    let reputation = &platform_account.reputation;
+    reviewer_account.penalize(reputation.penalty_weight as u64, reputation, Clock::get()?.unix_timestamp)
+}
@@ -0,0 +1,124 @@
+use anchor_lang::{prelude::*, system_program::{Transfer, transfer}};
+use crate::state::platform_account::{PlatformAccount, MAX_FEE_BPS, MAX_MODERATORS, MAX_TIP_MINTS};
+use crate::state::reputation::ReputationConfig;
+use crate::errors::PublishingPlatformError;
+
+#[derive(Accounts)]
+pub struct PlatformAdmin<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        has_one = admin @ PublishingPlatformError::UnauthorizedAdmin,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> PlatformAdmin<'info> {
+    pub fn update_platform_config(&mut self, fee_bps: u16) -> Result<()> {
+        require!(fee_bps <= MAX_FEE_BPS, PublishingPlatformError::InvalidFee);
+
+        self.platform_account.fee_bps = fee_bps;
+        Ok(())
+    }
+
+    pub fn set_allowed_tip_mints(&mut self, mints: Vec<Pubkey>) -> Result<()> {
+        require!(mints.len() <= MAX_TIP_MINTS, PublishingPlatformError::TooManyTipMints);
+
+        self.platform_account.allowed_tip_mints = mints;
+        Ok(())
+    }
+
+    pub fn set_moderators(&mut self, moderators: Vec<Pubkey>) -> Result<()> {
+        require!(moderators.len() <= MAX_MODERATORS, PublishingPlatformError::TooManyModerators);
+
+        self.platform_account.moderators = moderators;
+        Ok(())
+    }
+
+    pub fn set_reputation_config(&mut self, config: ReputationConfig) -> Result<()> {
+        config.validate()?;
+
+        self.platform_account.reputation = config;
+        Ok(())
+    }
+
+    pub fn transfer_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        // The new admin has to sign accept_admin before the handover takes effect
+        self.platform_account.pending_admin = Some(new_admin);
+        Ok(())
+    }
+
+    pub fn set_paused(&mut self, paused: bool) -> Result<()> {
+        self.platform_account.paused = paused;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = platform_account.pending_admin == Some(new_admin.key()) @ PublishingPlatformError::UnauthorizedAdmin,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn accept_admin(&mut self) -> Result<()> {
+        self.platform_account.admin = self.new_admin.key();
+        self.platform_account.pending_admin = None;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        has_one = admin @ PublishingPlatformError::UnauthorizedAdmin,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    #[account(
+        mut,
+        seeds = [b"treasury", platform_account.key().as_ref()],
+        bump = platform_account.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawTreasury<'info> {
+    pub fn withdraw_treasury(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, PublishingPlatformError::InvalidWithdrawAmount);
+
+        // The treasury always keeps its rent-exempt minimum
+        let available = self.treasury.lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(0));
+        require!(amount <= available, PublishingPlatformError::InsufficientTreasuryBalance);
+
+        let platform_key = self.platform_account.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"treasury",
+            platform_key.as_ref(),
+            &[self.platform_account.treasury_bump],
+        ]];
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.system_program.to_account_info(),
+            Transfer {
+                from: self.treasury.to_account_info(),
+                to: self.destination.to_account_info(),
+            },
+            signer_seeds,
+        );
+        transfer(cpi_ctx, amount)
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::Chapter;
+use crate::contexts::verify_content_access::check_chapter_access;
+use crate::errors::PublishingPlatformError;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+pub struct ReadChapter<'info> {
+    pub reader: Signer<'info>,
+    #[account(
+        seeds = [b"chapter", chapter.chapter_mint.as_ref()],
+        bump
+    )]
+    pub chapter: Account<'info, Chapter>,
+    // During early access, any chapter NFT of the same book the reader holds,
+    // with its Chapter account
+    #[account(
+        constraint = held_chapter.book_collection == chapter.book_collection @ PublishingPlatformError::ChapterNotInCollection
+    )]
+    pub held_chapter: Option<Account<'info, Chapter>>,
+    pub held_chapter_ata: Option<Account<'info, TokenAccount>>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> ReadChapter<'info> {
+    pub fn read_chapter(&self) -> Result<String> {
+        let reader = self.reader.key();
+
+        // Authors can always read their own chapters
+        if reader != self.chapter.author {
+            let is_collection_holder = match (self.held_chapter.as_deref(), self.held_chapter_ata.as_deref()) {
+                (Some(held_chapter), Some(held_chapter_ata)) => {
+                    check_chapter_access(&reader, held_chapter, Some(held_chapter_ata), None)?;
+                    true
+                }
+                _ => false,
+            };
+            self.chapter.ensure_readable(Clock::get()?.unix_timestamp, is_collection_holder)?;
+        }
+
+        Ok(self.chapter.content_uri.clone())
+    }
+}
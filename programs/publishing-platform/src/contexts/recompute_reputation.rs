@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::errors::PublishingPlatformError;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::user_account::ReaderAccount;
+
+// Permissionless crank, anyone can bring a reader's reputation up to date
+#[derive(Accounts)]
+pub struct RecomputeReputation<'info> {
+    #[account(
+        mut,
+        seeds = [b"reader", reader_account.wallet_address.as_ref()],
+        bump = reader_account.bump,
+    )]
+    pub reader_account: Account<'info, ReaderAccount>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> RecomputeReputation<'info> {
+    pub fn recompute_reputation(&mut self) -> Result<()> {
+        self.reader_account.apply_decay(&self.platform_account.reputation, Clock::get()?.unix_timestamp)
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::Chapter;
+use crate::errors::PublishingPlatformError;
+
+// The author can reveal at any time, anyone holding the preimage can once the
+// chapter is released
+#[derive(Accounts)]
+pub struct RevealChapter<'info> {
+    pub revealer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"chapter", chapter.chapter_mint.as_ref()],
+        bump
+    )]
+    pub chapter: Account<'info, Chapter>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> RevealChapter<'info> {
+    pub fn reveal_chapter(&mut self, content_uri: String, salt: [u8; 32]) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            self.revealer.key() == self.chapter.author || self.chapter.is_released(now),
+            PublishingPlatformError::RevealTooEarly
+        );
+
+        self.chapter.reveal(content_uri, salt, now)
+    }
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use crate::errors::PublishingPlatformError;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{Book, Chapter};
+use crate::state::review::{Review, ReviewKind, ReviewReply};
+use crate::state::user_account::WriterAccount;
+
+#[derive(Accounts)]
+pub struct ReplyToReview<'info> {
+    #[account(mut)]
+    pub writer: Signer<'info>,
+    #[account(
+        seeds = [b"review", review.reviewer.as_ref(), review.target.as_ref()],
+        bump = review.bump,
+        constraint = !review.hidden @ PublishingPlatformError::ReviewHidden,
+    )]
+    pub review: Account<'info, Review>,
+    // Only the author of the reviewed chapter, book or writer account can reply,
+    // only the account matching the review's kind needs to be passed
+    #[account(address = review.target)]
+    pub chapter: Option<Account<'info, Chapter>>,
+    #[account(
+        seeds = [b"book", review.book_collection.as_ref()],
+        bump
+    )]
+    pub book: Option<Account<'info, Book>>,
+    #[account(address = review.target)]
+    pub writer_account: Option<Account<'info, WriterAccount>>,
+    #[account(
+        init,
+        payer = writer,
+        space = 8 + ReviewReply::INIT_SPACE,
+        seeds = [b"reply", review.key().as_ref(), review.nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub review_reply: Account<'info, ReviewReply>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReplyToReview<'info> {
+    pub fn reply_to_review(&mut self, content: String, bumps: &ReplyToReviewBumps) -> Result<()> {
+        ReviewReply::validate(&content)?;
+
+        let author = match self.review.kind {
+            ReviewKind::Chapter => {
+                self.chapter.as_ref()
+                    .ok_or(PublishingPlatformError::ChapterAccountMissing)?
+                    .author
+            }
+            ReviewKind::Book => {
+                self.book.as_ref()
+                    .ok_or(PublishingPlatformError::BookAccountMissing)?
+                    .author
+            }
+            ReviewKind::Writer => {
+                self.writer_account.as_ref()
+                    .ok_or(PublishingPlatformError::WriterAccountNotFound)?
+                    .wallet_address
+            }
+        };
+        require_keys_eq!(author, self.writer.key(), PublishingPlatformError::UnauthorizedWriter);
+
+        self.review_reply.set_inner(ReviewReply {
+            review: self.review.key(),
+            review_nonce: self.review.nonce,
+            author: self.writer.key(),
+            content,
+            created_at: Clock::get()?.unix_timestamp,
+            updated_at: None,
+            bump: bumps.review_reply,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct EditReviewReply<'info> {
+    pub writer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"reply", review_reply.review.as_ref(), review_reply.review_nonce.to_le_bytes().as_ref()],
+        bump = review_reply.bump,
+        constraint = review_reply.author == writer.key() @ PublishingPlatformError::UnauthorizedWriter
+    )]
+    pub review_reply: Account<'info, ReviewReply>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> EditReviewReply<'info> {
+    pub fn edit_review_reply(&mut self, content: String) -> Result<()> {
+        ReviewReply::validate(&content)?;
+
+        self.review_reply.content = content;
+        self.review_reply.updated_at = Some(Clock::get()?.unix_timestamp);
+        Ok(())
+    }
+}
+
+// Does not need the review itself, so replies can still be cleaned up after
+// the review was deleted or removed
+#[derive(Accounts)]
+pub struct DeleteReviewReply<'info> {
+    #[account(mut)]
+    pub writer: Signer<'info>,
+    #[account(
+        mut,
+        close = writer,
+        seeds = [b"reply", review_reply.review.as_ref(), review_reply.review_nonce.to_le_bytes().as_ref()],
+        bump = review_reply.bump,
+        constraint = review_reply.author == writer.key() @ PublishingPlatformError::UnauthorizedWriter
+    )]
+    pub review_reply: Account<'info, ReviewReply>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> DeleteReviewReply<'info> {
+    pub fn delete_review_reply(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
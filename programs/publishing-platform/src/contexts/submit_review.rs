@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
+use crate::state::platform_account::PlatformAccount;
 use anchor_spl::token::TokenAccount;
-use crate::state::{Review, Chapter};
+use crate::state::{Review, ReviewKind, RatingTargets, Chapter, Book};
 use crate::state::user_account::ReaderAccount;
+use crate::state::chapter_unlock::ChapterUnlock;
+use crate::state::subscription::{Subscription, SubscriptionPlan};
+use crate::state::access_policy::HoldingProof;
+use crate::contexts::verify_content_access::check_chapter_access;
 
 use crate::errors::PublishingPlatformError;
 
@@ -18,11 +23,22 @@ pub struct SubmitReview<'info> {
     pub reader_account: Account<'info, ReaderAccount>,
     #[account(
         mut,
-        seeds = [b"chapter", chapter_ata.mint.as_ref()],
+        seeds = [b"chapter", chapter.chapter_mint.as_ref()],
         bump
     )]
     pub chapter: Account<'info, Chapter>,
-    pub chapter_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"book", chapter.book_collection.as_ref()],
+        bump
+    )]
+    pub book: Account<'info, Book>,
+    // Either the reviewer's chapter NFT, their paid unlock receipt, or a
+    // subscription (with its plan) covering the chapter's book
+    pub chapter_ata: Option<Account<'info, TokenAccount>>,
+    pub chapter_unlock: Option<Account<'info, ChapterUnlock>>,
+    pub subscription: Option<Account<'info, Subscription>>,
+    pub subscription_plan: Option<Account<'info, SubscriptionPlan>>,
 
     #[account(
         init,
@@ -36,6 +52,12 @@ pub struct SubmitReview<'info> {
         bump,
     )]
     pub review: Account<'info, Review>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
     pub system_program: Program<'info, System>,
 }
 
@@ -44,44 +66,81 @@ impl<'info> SubmitReview<'info> {
         &mut self,
         content: String,
         rating: u8,
+        bumps: &SubmitReviewBumps,
     ) -> Result<()> {
+        Review::validate(&content, rating)?;
+        let now = Clock::get()?.unix_timestamp;
 
-        require!(
-            rating >= 1 && rating <= 5,
-            PublishingPlatformError::InvalidRating
-        );
+        let proof = self.holding_proof(now)?;
+        // Only holders of this chapter's NFT may review it during early access
+        self.chapter.ensure_readable(now, proof == HoldingProof::ChapterNft)?;
+        let verified_holder = proof.is_purchase();
+
+        let reputation = &self.platform_account.reputation;
+        self.reader_account.apply_decay(reputation, now)?;
+        let weight = reputation.rating_weight(self.reader_account.reputation_score);
 
         // Initialize the review
         self.review.set_inner(Review {
             reviewer: self.reviewer.key(),
-            chapter: self.chapter.key(),
+            target: self.chapter.key(),
+            kind: ReviewKind::Chapter,
             book_collection: self.chapter.book_collection,
             content,
             rating,
             upvotes: 0,
-            created_at: Clock::get()?.unix_timestamp,
+            created_at: now,
+            bump: bumps.review,
+            updated_at: None,
+            weight,
+            flag_count: 0,
+            hidden: false,
+            verified_holder,
+            proof,
+            nonce: self.reader_account.reviews_submitted,
         });
 
         // Update reader profile
         self.reader_account.review_count += 1;
-        self.reader_account.total_upvotes += 0;
-        self.reader_account.reputation_score += 50;
+        self.reader_account.reviews_submitted = self.reader_account.reviews_submitted.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.reader_account.reward(reputation.review_reward(verified_holder), reputation, now)?;
 
-        // Update chapter rating
-        let total_rating = (self.chapter.rating as u32)
-            .checked_mul(self.chapter.review_count)
-            .and_then(|x| x.checked_add(rating as u32));
+        // Update chapter rating and roll it up into the book
+        self.chapter.review_count = self.chapter.review_count.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.review.add_rating(RatingTargets {
+            chapter: Some(&mut self.chapter),
+            book: Some(&mut self.book),
+            ..Default::default()
+        })?;
 
-        self.chapter.review_count += 1;
-        self.chapter.rating = (total_rating.unwrap()
-            .checked_div(self.chapter.review_count)
-            .unwrap()) as u8;
-        /*let mut total_rating = self.chapter.rating as u32 * self.chapter.review_count;
-        total_rating += rating as u32;
-        self.chapter.review_count += 1;
-        self.chapter.rating = (total_rating / self.chapter.review_count) as u8;*/
+        Ok(())
+    }
 
+    // Only readers who can actually access the chapter may review it
+    fn holding_proof(&self, now: i64) -> Result<HoldingProof> {
+        if self.chapter_ata.is_none() && self.chapter_unlock.is_none() {
+            if let Some(subscription) = self.subscription.as_deref() {
+                let plan = self.subscription_plan.as_ref()
+                    .ok_or(PublishingPlatformError::InvalidSubscription)?;
+                subscription.ensure_grants(
+                    &self.reviewer.key(),
+                    &plan.key(),
+                    plan,
+                    &self.chapter.author,
+                    &self.chapter.book_collection,
+                    now,
+                )?;
+                return Ok(HoldingProof::Subscription);
+            }
+        }
 
-        Ok(())
+        check_chapter_access(
+            &self.reviewer.key(),
+            &self.chapter,
+            self.chapter_ata.as_deref(),
+            self.chapter_unlock.as_deref(),
+        )
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::state::platform_account::PlatformAccount;
+use anchor_spl::token::TokenAccount;
+use crate::state::{Review, ReviewKind, RatingTargets, Chapter};
+use crate::state::user_account::{ReaderAccount, WriterAccount};
+use crate::state::chapter_unlock::ChapterUnlock;
+use crate::contexts::verify_content_access::check_chapter_access;
+
+use crate::errors::PublishingPlatformError;
+
+#[derive(Accounts)]
+pub struct SubmitWriterReview<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"reader", reviewer.key().as_ref()],
+        bump
+    )]
+    pub reader_account: Account<'info, ReaderAccount>,
+    #[account(
+        mut,
+        seeds = [b"writer", writer_account.wallet_address.as_ref()],
+        bump = writer_account.bump,
+        constraint = writer_account.wallet_address != reviewer.key() @ PublishingPlatformError::CannotReviewSelf
+    )]
+    pub writer_account: Account<'info, WriterAccount>,
+    // Any chapter by the writer the reviewer holds, with their NFT or unlock receipt for it
+    #[account(
+        seeds = [b"chapter", chapter.chapter_mint.as_ref()],
+        bump,
+        constraint = chapter.author == writer_account.wallet_address @ PublishingPlatformError::UnauthorizedWriter
+    )]
+    pub chapter: Account<'info, Chapter>,
+    pub chapter_ata: Option<Account<'info, TokenAccount>>,
+    pub chapter_unlock: Option<Account<'info, ChapterUnlock>>,
+
+    #[account(
+        init,
+        payer = reviewer,
+        space = 8 + Review::INIT_SPACE,
+        seeds = [
+            b"review",
+            reviewer.key().as_ref(),
+            writer_account.key().as_ref()
+        ],
+        bump,
+    )]
+    pub review: Account<'info, Review>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SubmitWriterReview<'info> {
+    pub fn submit_writer_review(
+        &mut self,
+        content: String,
+        rating: u8,
+        bumps: &SubmitWriterReviewBumps,
+    ) -> Result<()> {
+        Review::validate(&content, rating)?;
+        let now = Clock::get()?.unix_timestamp;
+
+        let proof = check_chapter_access(
+            &self.reviewer.key(),
+            &self.chapter,
+            self.chapter_ata.as_deref(),
+            self.chapter_unlock.as_deref(),
+        )?;
+        let verified_holder = proof.is_purchase();
+
+        let reputation = &self.platform_account.reputation;
+        self.reader_account.apply_decay(reputation, now)?;
+        let weight = reputation.rating_weight(self.reader_account.reputation_score);
+
+        self.review.set_inner(Review {
+            reviewer: self.reviewer.key(),
+            target: self.writer_account.key(),
+            kind: ReviewKind::Writer,
+            // The collection of the chapter the reviewer proved access with
+            book_collection: self.chapter.book_collection,
+            content,
+            rating,
+            upvotes: 0,
+            created_at: now,
+            bump: bumps.review,
+            updated_at: None,
+            weight,
+            flag_count: 0,
+            hidden: false,
+            verified_holder,
+            proof,
+            nonce: self.reader_account.reviews_submitted,
+        });
+
+        self.reader_account.review_count += 1;
+        self.reader_account.reviews_submitted = self.reader_account.reviews_submitted.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.reader_account.reward(reputation.review_reward(verified_holder), reputation, now)?;
+
+        self.review.add_rating(RatingTargets {
+            writer: Some(&mut self.writer_account),
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use crate::errors::PublishingPlatformError;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::subscription::{Subscription, SubscriptionPlan};
+
+#[derive(Accounts)]
+pub struct Subscribe<'info> {
+    #[account(mut)]
+    pub reader: Signer<'info>,
+    #[account(
+        mut,
+        address = subscription_plan.writer @ PublishingPlatformError::WriterAccountNotFound
+    )]
+    pub writer: SystemAccount<'info>,
+    #[account(
+        seeds = [
+            b"plan",
+            subscription_plan.writer.as_ref(),
+            subscription_plan.index.to_le_bytes().as_ref()
+        ],
+        bump = subscription_plan.bump,
+    )]
+    pub subscription_plan: Account<'info, SubscriptionPlan>,
+    #[account(
+        init,
+        payer = reader,
+        space = 8 + Subscription::INIT_SPACE,
+        seeds = [
+            b"subscription",
+            reader.key().as_ref(),
+            subscription_plan.key().as_ref()
+        ],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    #[account(
+        mut,
+        seeds = [b"treasury", platform_account.key().as_ref()],
+        bump = platform_account.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Subscribe<'info> {
+    pub fn subscribe(&mut self, periods: u32, bumps: &SubscribeBumps) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        self.subscription.set_inner(Subscription {
+            reader: self.reader.key(),
+            plan: self.subscription_plan.key(),
+            started_at: now,
+            expires_at: extend_expiry(&self.subscription_plan, now, periods)?,
+            bump: bumps.subscription,
+        });
+
+        let amount = self.subscription_plan.price_per_period
+            .checked_mul(periods as u64)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+
+        self.platform_account.collect_payment(
+            amount,
+            self.system_program.to_account_info(),
+            self.reader.to_account_info(),
+            self.writer.to_account_info(),
+            self.treasury.to_account_info(),
+        )?;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Renew<'info> {
+    #[account(mut)]
+    pub reader: Signer<'info>,
+    #[account(
+        mut,
+        address = subscription_plan.writer @ PublishingPlatformError::WriterAccountNotFound
+    )]
+    pub writer: SystemAccount<'info>,
+    #[account(
+        seeds = [
+            b"plan",
+            subscription_plan.writer.as_ref(),
+            subscription_plan.index.to_le_bytes().as_ref()
+        ],
+        bump = subscription_plan.bump,
+    )]
+    pub subscription_plan: Account<'info, SubscriptionPlan>,
+    #[account(
+        mut,
+        seeds = [
+            b"subscription",
+            reader.key().as_ref(),
+            subscription_plan.key().as_ref()
+        ],
+        bump = subscription.bump,
+    )]
+    pub subscription: Account<'info, Subscription>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    #[account(
+        mut,
+        seeds = [b"treasury", platform_account.key().as_ref()],
+        bump = platform_account.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Renew<'info> {
+    pub fn renew(&mut self, periods: u32) -> Result<()> {
+        // Renewing early stacks on top of the remaining time instead of discarding it
+        let now = Clock::get()?.unix_timestamp;
+        let from = self.subscription.expires_at.max(now);
+        self.subscription.expires_at = extend_expiry(&self.subscription_plan, from, periods)?;
+
+        let amount = self.subscription_plan.price_per_period
+            .checked_mul(periods as u64)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+
+        self.platform_account.collect_payment(
+            amount,
+            self.system_program.to_account_info(),
+            self.reader.to_account_info(),
+            self.writer.to_account_info(),
+            self.treasury.to_account_info(),
+        )?;
+        Ok(())
+    }
+}
+
+fn extend_expiry(plan: &SubscriptionPlan, from: i64, periods: u32) -> Result<i64> {
+    require!(periods > 0, PublishingPlatformError::InvalidSubscriptionPeriod);
+
+    plan.period_seconds
+        .checked_mul(periods as i64)
+        .and_then(|x| x.checked_add(from))
+        .ok_or(PublishingPlatformError::ArithmeticOverflow.into())
+}
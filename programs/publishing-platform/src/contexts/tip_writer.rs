@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
+use crate::state::platform_account::PlatformAccount;
 use crate::errors::PublishingPlatformError;
+use crate::events::TipSent;
 use crate::state::user_account::WriterAccount;
+use crate::state::content::{Book, Chapter};
+use crate::state::tip_record::{TipRecord, TipTarget, MAX_TIP_MESSAGE_LEN};
 
 #[derive(Accounts)]
 pub struct TipWriter<'info> {
@@ -9,33 +13,141 @@ pub struct TipWriter<'info> {
     #[account(mut)]
     pub writer: SystemAccount<'info>,
     #[account(
+        mut,
         seeds = [b"writer".as_ref(), writer.key().as_ref()],
         bump,
         constraint = writer_account.wallet_address == writer.key() @ PublishingPlatformError::WriterAccountNotFound
     )]
     pub writer_account: Account<'info, WriterAccount>,
+    // Optional tip attribution: a book, or a chapter together with its book
+    #[account(
+        mut,
+        seeds = [b"book", book.collection_mint.as_ref()],
+        bump,
+        constraint = book.contributor(&writer.key()).is_some() @ PublishingPlatformError::InvalidTipTarget
+    )]
+    pub book: Option<Account<'info, Book>>,
+    #[account(
+        seeds = [b"chapter", chapter.chapter_mint.as_ref()],
+        bump,
+    )]
+    pub chapter: Option<Account<'info, Chapter>>,
+    // Readers who do not want to pay rent for a receipt leave this out and rely on the TipSent event
+    #[account(
+        init,
+        payer = reader,
+        space = 8 + TipRecord::INIT_SPACE,
+        seeds = [
+            b"tip",
+            writer.key().as_ref(),
+            writer_account.tip_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub tip_record: Option<Account<'info, TipRecord>>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> TipWriter<'info> {
-    pub fn tip_writer(&mut self, amount: u64) -> Result<()> {
+    // Tips attributed to a book or chapter are split across the book's contributors,
+    // whose wallets are passed as remaining accounts in contributor order. Passing a
+    // contributor's WriterAccount after their wallet credits the tip to their totals.
+    pub fn tip_writer(&mut self, amount: u64, message: String, contributor_wallets: &[AccountInfo<'info>], bumps: &TipWriterBumps) -> Result<()> {
         
         if amount == 0 {
             return Err(PublishingPlatformError::ZeroTipAmount.into());
         }
+        require!(
+            message.len() <= MAX_TIP_MESSAGE_LEN,
+            PublishingPlatformError::TipMessageTooLong
+        );
 
-        // Create the transfer instruction
-        anchor_lang::system_program::transfer(
-            CpiContext::new(
-                self.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: self.reader.to_account_info(),
-                    to: self.writer.to_account_info(),
-                },
-            ),
+        let target = self.tip_target()?;
+
+        let received = match self.book.as_mut() {
+            Some(book) => {
+                book.total_tips = book.total_tips.checked_add(amount)
+                    .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+                book.distribute(
+                    amount,
+                    &self.writer.key(),
+                    self.system_program.to_account_info(),
+                    self.reader.to_account_info(),
+                    contributor_wallets,
+                    |account, share| {
+                        account.total_tips_received = account.total_tips_received.checked_add(share)
+                            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+                        Ok(())
+                    },
+                )?
+            }
+            None => {
+                // Create the transfer instruction
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: self.reader.to_account_info(),
+                            to: self.writer.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
+                amount
+            }
+        };
+
+        let index = self.writer_account.tip_count;
+        self.writer_account.tip_count = index.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.writer_account.total_tips_received = self.writer_account.total_tips_received.checked_add(received)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+
+        if let Some(tip_record) = self.tip_record.as_mut() {
+            tip_record.set_inner(TipRecord {
+                tipper: self.reader.key(),
+                writer: self.writer.key(),
+                target,
+                amount,
+                message: message.clone(),
+                index,
+                created_at: Clock::get()?.unix_timestamp,
+                bump: bumps.tip_record.unwrap_or_default(),
+            });
+        }
+
+        emit!(TipSent {
+            tipper: self.reader.key(),
+            writer: self.writer.key(),
+            target,
             amount,
-        )?;
+            message,
+            index,
+        });
         
         Ok(())
     }
-}
\ No newline at end of file
+
+    fn tip_target(&self) -> Result<TipTarget> {
+        match (&self.book, &self.chapter) {
+            (None, None) => Ok(TipTarget::Writer),
+            (Some(book), None) => Ok(TipTarget::Book(book.key())),
+            (Some(book), Some(chapter)) => {
+                // Chapter tips also count towards their book's totals
+                require_keys_eq!(
+                    chapter.book_collection,
+                    book.collection_mint,
+                    PublishingPlatformError::InvalidTipTarget
+                );
+                Ok(TipTarget::Chapter(chapter.key()))
+            }
+            (None, Some(_)) => err!(PublishingPlatformError::InvalidTipTarget),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
+};
+use crate::state::platform_account::PlatformAccount;
+use crate::errors::PublishingPlatformError;
+use crate::state::user_account::WriterAccount;
+
+#[derive(Accounts)]
+pub struct TipWriterToken<'info> {
+    #[account(mut)]
+    pub reader: Signer<'info>,
+    pub writer: SystemAccount<'info>,
+    #[account(
+        seeds = [b"writer".as_ref(), writer.key().as_ref()],
+        bump,
+        constraint = writer_account.wallet_address == writer.key() @ PublishingPlatformError::WriterAccountNotFound
+    )]
+    pub writer_account: Account<'info, WriterAccount>,
+    #[account(
+        mint::token_program = token_program,
+        constraint = platform_account.allowed_tip_mints.contains(&mint.key()) @ PublishingPlatformError::TipMintNotAllowed
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = reader,
+        associated_token::token_program = token_program,
+    )]
+    pub reader_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = reader,
+        associated_token::mint = mint,
+        associated_token::authority = writer,
+        associated_token::token_program = token_program,
+    )]
+    pub writer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TipWriterToken<'info> {
+    pub fn tip_writer_token(&mut self, amount: u64) -> Result<()> {
+
+        if amount == 0 {
+            return Err(PublishingPlatformError::ZeroTipAmount.into());
+        }
+
+        let accounts = TransferChecked {
+            from: self.reader_ata.to_account_info(),
+            to: self.writer_ata.to_account_info(),
+            authority: self.reader.to_account_info(),
+            mint: self.mint.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), accounts);
+
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)
+    }
+}
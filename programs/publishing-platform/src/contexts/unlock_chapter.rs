@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{Chapter, ChapterStatus, Book};
+use crate::state::user_account::WriterAccount;
+use crate::state::chapter_unlock::ChapterUnlock;
+use crate::errors::PublishingPlatformError;
+
+#[derive(Accounts)]
+pub struct UnlockChapter<'info> {
+    #[account(mut)]
+    pub reader: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"writer".as_ref(), chapter.author.as_ref()],
+        bump = writer_account.bump,
+    )]
+    pub writer_account: Account<'info, WriterAccount>,
+    #[account(
+        seeds = [b"chapter", chapter.chapter_mint.as_ref()],
+        bump,
+        constraint = chapter.status == ChapterStatus::Published @ PublishingPlatformError::ChapterNotPublished
+    )]
+    pub chapter: Account<'info, Chapter>,
+    #[account(
+        mut,
+        seeds = [b"book", chapter.book_collection.as_ref()],
+        bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        init,
+        payer = reader,
+        space = 8 + ChapterUnlock::INIT_SPACE,
+        seeds = [
+            b"unlock",
+            reader.key().as_ref(),
+            chapter.key().as_ref()
+        ],
+        bump
+    )]
+    pub chapter_unlock: Account<'info, ChapterUnlock>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    #[account(
+        mut,
+        seeds = [b"treasury", platform_account.key().as_ref()],
+        bump = platform_account.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UnlockChapter<'info> {
+    // The price, minus the platform fee, is split across the book's contributors,
+    // whose wallets are passed as remaining accounts in contributor order, each
+    // optionally followed by their WriterAccount to record their royalties
+    pub fn unlock_chapter(&mut self, contributor_wallets: &[AccountInfo<'info>], bumps: &UnlockChapterBumps) -> Result<()> {
+        let price = self.chapter.price_lamports
+            .ok_or(PublishingPlatformError::ChapterNotForSale)?;
+
+        let net = self.platform_account.collect_fee(
+            price,
+            self.system_program.to_account_info(),
+            self.reader.to_account_info(),
+            self.treasury.to_account_info(),
+        )?;
+        let royalties = self.book.distribute(
+            net,
+            &self.chapter.author,
+            self.system_program.to_account_info(),
+            self.reader.to_account_info(),
+            contributor_wallets,
+            |account, share| {
+                account.total_royalties = account.total_royalties.checked_add(share)
+                    .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+                Ok(())
+            },
+        )?;
+
+        self.book.total_sales = self.book.total_sales.checked_add(price)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.writer_account.total_royalties = self.writer_account.total_royalties.checked_add(royalties)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+
+        self.chapter_unlock.set_inner(ChapterUnlock {
+            reader: self.reader.key(),
+            chapter: self.chapter.key(),
+            chapter_mint: self.chapter.chapter_mint,
+            price_paid: price,
+            unlocked_at: Clock::get()?.unix_timestamp,
+            bump: bumps.chapter_unlock,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{Book, BookStatus, Contributor, MAX_EDITORS};
+use crate::state::user_account::WriterAccount;
+use crate::errors::PublishingPlatformError;
+
+#[derive(Accounts)]
+pub struct UpdateBook<'info> {
+    pub writer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"book", book.collection_mint.as_ref()],
+        bump,
+        constraint = book.author == writer.key() @ PublishingPlatformError::UnauthorizedWriter
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> UpdateBook<'info> {
+    pub fn update_book(&mut self, title: String, genre: String, royalty_percentage: u8) -> Result<()> {
+        Book::validate_details(&title, &genre, royalty_percentage)?;
+
+        self.book.title = title;
+        self.book.genre = genre;
+        self.book.royalty_percentage = royalty_percentage;
+        Ok(())
+    }
+
+    // Removed editors sign through remaining_accounts, so the author cannot drop
+    // them on their own and publish chapters that were never approved
+    pub fn set_book_editors(&mut self, editors: Vec<Pubkey>, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        require!(editors.len() <= MAX_EDITORS, PublishingPlatformError::TooManyEditors);
+        require!(
+            !editors.iter().any(|editor| self.book.can_write(editor)),
+            PublishingPlatformError::EditorCannotWrite
+        );
+
+        for removed in self.book.editors.iter().filter(|editor| !editors.contains(editor)) {
+            require!(
+                remaining_accounts.iter().any(|account| account.key == removed && account.is_signer),
+                PublishingPlatformError::EditorRemovalNotSigned
+            );
+        }
+
+        self.book.editors = editors;
+        Ok(())
+    }
+
+    pub fn set_book_status(&mut self, status: BookStatus) -> Result<()> {
+        self.book.status = status;
+        Ok(())
+    }
+
+    pub fn set_book_contributors(&mut self, contributors: Vec<Contributor>) -> Result<()> {
+        Book::validate_contributors(&contributors)?;
+        // Same rule as set_book_editors, from the other side
+        require!(
+            !contributors.iter().any(|c| c.can_write && self.book.editors.contains(&c.wallet)),
+            PublishingPlatformError::EditorCannotWrite
+        );
+
+        self.book.contributors = contributors;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CloseBook<'info> {
+    #[account(mut)]
+    pub writer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"writer", writer.key().as_ref()],
+        bump = writer_account.bump,
+    )]
+    pub writer_account: Account<'info, WriterAccount>,
+    #[account(
+        mut,
+        close = writer,
+        seeds = [b"book", book.collection_mint.as_ref()],
+        bump,
+        constraint = book.author == writer.key() @ PublishingPlatformError::UnauthorizedWriter,
+        constraint = book.chapter_count == 0 @ PublishingPlatformError::BookHasChapters,
+        constraint = book.live_exclusive_content == 0 @ PublishingPlatformError::BookHasExclusiveContent,
+        // Book reviews need the book account to be edited or deleted
+        constraint = book.book_rating.rating_count == 0 @ PublishingPlatformError::BookHasReviews
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> CloseBook<'info> {
+    pub fn close_book(&mut self) -> Result<()> {
+        self.writer_account.book_count = self.writer_account.book_count.saturating_sub(1);
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{Book, Chapter, ChapterStatus, ReleaseSchedule};
+use crate::state::chapter_revision::ChapterRevision;
+use crate::errors::PublishingPlatformError;
+
+#[derive(Accounts)]
+pub struct UpdateChapter<'info> {
+    pub writer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"chapter", chapter.chapter_mint.as_ref()],
+        bump,
+        constraint = chapter.author == writer.key() @ PublishingPlatformError::UnauthorizedWriter
+    )]
+    pub chapter: Account<'info, Chapter>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> UpdateChapter<'info> {
+    pub fn set_chapter_price(&mut self, price_lamports: Option<u64>) -> Result<()> {
+        require!(price_lamports != Some(0), PublishingPlatformError::InvalidChapterPrice);
+
+        self.chapter.price_lamports = price_lamports;
+        Ok(())
+    }
+
+    pub fn set_release_schedule(&mut self, schedule: ReleaseSchedule) -> Result<()> {
+        schedule.validate()?;
+        // Pulling a chapter back out of public release is not possible
+        require!(
+            !self.chapter.is_released(Clock::get()?.unix_timestamp),
+            PublishingPlatformError::ChapterAlreadyReleased
+        );
+
+        self.chapter.early_access_at = schedule.early_access_at;
+        self.chapter.release_at = schedule.release_at;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ReviseChapter<'info> {
+    #[account(mut)]
+    pub writer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"chapter", chapter.chapter_mint.as_ref()],
+        bump,
+        constraint = chapter.author == writer.key() @ PublishingPlatformError::UnauthorizedWriter
+    )]
+    pub chapter: Account<'info, Chapter>,
+    #[account(
+        seeds = [b"book", chapter.book_collection.as_ref()],
+        bump
+    )]
+    pub book: Account<'info, Book>,
+    // Keeps the version being replaced, indexed by its revision number
+    #[account(
+        init,
+        payer = writer,
+        space = 8 + ChapterRevision::INIT_SPACE,
+        seeds = [b"revision", chapter.key().as_ref(), chapter.revision.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chapter_revision: Account<'info, ChapterRevision>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReviseChapter<'info> {
+    pub fn revise_chapter(
+        &mut self,
+        content_uri: String,
+        content_hash: [u8; 32],
+        bumps: &ReviseChapterBumps,
+    ) -> Result<()> {
+        Chapter::validate_content_uri(&content_uri)?;
+        require!(self.chapter.is_revealed(), PublishingPlatformError::ChapterNotRevealed);
+
+        self.chapter_revision.set_inner(ChapterRevision {
+            chapter: self.chapter.key(),
+            revision: self.chapter.revision,
+            content_uri: std::mem::replace(&mut self.chapter.content_uri, content_uri),
+            content_hash: std::mem::replace(&mut self.chapter.content_hash, content_hash),
+            replaced_at: Clock::get()?.unix_timestamp,
+            bump: bumps.chapter_revision,
+        });
+
+        self.chapter.revision = self.chapter.revision.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+
+        // Editors only approved the previous content, the revision goes back through review
+        if !self.book.editors.is_empty() {
+            self.chapter.status = ChapterStatus::Draft;
+            self.chapter.reviewed_by = None;
+            self.chapter.editorial_note.clear();
+        }
+        Ok(())
+    }
+}
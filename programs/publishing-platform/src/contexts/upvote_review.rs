@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use crate::state::platform_account::PlatformAccount;
+use crate::state::review::{Review, ReviewVote};
+use crate::state::user_account::ReaderAccount;
+use crate::errors::PublishingPlatformError;
+
+#[derive(Accounts)]
+pub struct UpvoteReview<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    // Only registered readers can vote
+    #[account(
+        seeds = [b"reader", voter.key().as_ref()],
+        bump = voter_account.bump,
+    )]
+    pub voter_account: Account<'info, ReaderAccount>,
+    #[account(
+        mut,
+        seeds = [b"review", review.reviewer.as_ref(), review.target.as_ref()],
+        bump = review.bump,
+        constraint = review.reviewer != voter.key() @ PublishingPlatformError::SelfVoteNotAllowed,
+        constraint = !review.hidden @ PublishingPlatformError::ReviewHidden,
+    )]
+    pub review: Account<'info, Review>,
+    #[account(
+        mut,
+        seeds = [b"reader", review.reviewer.as_ref()],
+        bump = reviewer_account.bump,
+    )]
+    pub reviewer_account: Account<'info, ReaderAccount>,
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + ReviewVote::INIT_SPACE,
+        seeds = [b"vote", voter.key().as_ref(), review.key().as_ref(), review.nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub review_vote: Account<'info, ReviewVote>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpvoteReview<'info> {
+    pub fn upvote_review(&mut self, bumps: &UpvoteReviewBumps) -> Result<()> {
+        self.review_vote.set_inner(ReviewVote {
+            voter: self.voter.key(),
+            review: self.review.key(),
+            review_nonce: self.review.nonce,
+            created_at: Clock::get()?.unix_timestamp,
+            bump: bumps.review_vote,
+        });
+
+        self.review.upvotes = self.review.upvotes.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.reviewer_account.total_upvotes = self.reviewer_account.total_upvotes.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        let reputation = &self.platform_account.reputation;
+        self.reviewer_account.reward(reputation.upvote_weight as u64, reputation, Clock::get()?.unix_timestamp)?;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RemoveUpvote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    /// CHECK: the voted review may since have been deleted or submitted again,
+    /// it is only loaded when it still carries the vote's nonce
+    #[account(mut, address = review_vote.review)]
+    pub review: UncheckedAccount<'info>,
+    // Only needed while the voted review still exists
+    #[account(mut)]
+    pub reviewer_account: Option<Account<'info, ReaderAccount>>,
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"vote", voter.key().as_ref(), review_vote.review.as_ref(), review_vote.review_nonce.to_le_bytes().as_ref()],
+        bump = review_vote.bump,
+    )]
+    pub review_vote: Account<'info, ReviewVote>,
+    #[account(
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
+    )]
+    pub platform_account: Account<'info, PlatformAccount>,
+}
+
+impl<'info> RemoveUpvote<'info> {
+    pub fn remove_upvote(&mut self) -> Result<()> {
+        // Deleting or removing a review already took its upvotes off the reviewer,
+        // so a vote on a review that is gone only gives its rent back
+        if self.review.owner != &crate::ID || self.review.data_is_empty() {
+            return Ok(());
+        }
+        let mut review = Review::try_deserialize(&mut &self.review.try_borrow_data()?[..])?;
+        if review.nonce != self.review_vote.review_nonce {
+            return Ok(());
+        }
+
+        let reviewer_account = self.reviewer_account.as_mut()
+            .ok_or(PublishingPlatformError::ReviewerAccountMismatch)?;
+        require_keys_eq!(
+            reviewer_account.wallet_address,
+            review.reviewer,
+            PublishingPlatformError::ReviewerAccountMismatch
+        );
+
+        review.upvotes = review.upvotes.saturating_sub(1);
+        review.try_serialize(&mut &mut self.review.try_borrow_mut_data()?[..])?;
+
+        reviewer_account.total_upvotes = reviewer_account.total_upvotes.saturating_sub(1);
+        let reputation = &self.platform_account.reputation;
+        reviewer_account.penalize(reputation.upvote_weight as u64, reputation, Clock::get()?.unix_timestamp)?;
+        Ok(())
+    }
+}
@@ -1,5 +1,9 @@
-use anchor_lang::prelude::*;
-use crate::state::content::{ExclusiveContent, Chapter};
+use anchor_lang::{prelude::*, Discriminator};
+use crate::state::platform_account::PlatformAccount;
+use crate::state::content::{ExclusiveContent, Chapter, Book};
+use crate::state::access_policy::{HeldChapter, BookChapterCount, HoldingProof};
+use crate::state::subscription::{Subscription, SubscriptionPlan};
+use crate::state::chapter_unlock::ChapterUnlock;
 use crate::errors::PublishingPlatformError;
 use anchor_spl::token::TokenAccount;
 
@@ -8,29 +12,181 @@ pub struct VerifyAccess<'info> {
     pub reader: Signer<'info>,
     #[account(mut)]
     pub exclusive_content: Account<'info, ExclusiveContent>,
-    pub chapter_ata: Account<'info, TokenAccount>,
+    // NFT holders pass their chapter token account and the matching chapter,
+    // readers who paid for the chapter pass their unlock receipt instead
+    pub chapter: Option<Account<'info, Chapter>>,
+    pub chapter_ata: Option<Account<'info, TokenAccount>>,
+    pub chapter_unlock: Option<Account<'info, ChapterUnlock>>,
+    // Subscribers pass their subscription and its plan instead
+    pub subscription: Option<Account<'info, Subscription>>,
+    pub subscription_plan: Option<Account<'info, SubscriptionPlan>>,
     #[account(
-        seeds = [b"chapter", chapter_ata.mint.as_ref()],
-        bump
+        seeds = [b"platform"],
+        bump = platform_account.bump,
+        constraint = !platform_account.paused @ PublishingPlatformError::PlatformPaused,
     )]
-    pub chapter: Account<'info, Chapter>,
+    pub platform_account: Account<'info, PlatformAccount>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> VerifyAccess<'info> {   
-    pub fn verify_access(&self) -> Result<String> {
-        // Verify the reader owns an NFT from the required collection
+    pub fn verify_access(&self, remaining_accounts: &[AccountInfo]) -> Result<String> {
+        check_access(
+            &self.reader.key(),
+            &self.exclusive_content,
+            AccessProof {
+                chapter: self.chapter.as_deref(),
+                chapter_ata: self.chapter_ata.as_deref(),
+                chapter_unlock: self.chapter_unlock.as_deref(),
+                subscription: self.subscription.as_deref(),
+                subscription_plan: self.subscription_plan.as_ref(),
+            },
+            remaining_accounts,
+        )?;
+        
+        // Return the content_uri
+        Ok(self.exclusive_content.content_uri.clone()) 
+    }
+}
+
+// The optional accounts a reader can present to prove access
+pub struct AccessProof<'a, 'info> {
+    pub chapter: Option<&'a Chapter>,
+    pub chapter_ata: Option<&'a TokenAccount>,
+    pub chapter_unlock: Option<&'a ChapterUnlock>,
+    pub subscription: Option<&'a Subscription>,
+    pub subscription_plan: Option<&'a Account<'info, SubscriptionPlan>>,
+}
+
+// Shared by verify_access and grant_access_pass so both enforce the same rules.
+// An active subscription to a plan covering the content is enough on its own.
+// Otherwise the access policy is evaluated over the reader's holdings: extra
+// holdings are passed as remaining accounts, each token account or unlock receipt
+// followed by its Chapter account, and Book accounts may appear anywhere for
+// HoldsFullBook.
+pub fn check_access(
+    reader: &Pubkey,
+    exclusive_content: &ExclusiveContent,
+    proof: AccessProof,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    exclusive_content.ensure_available(now)?;
+
+    if let Some(subscription) = proof.subscription {
+        let plan = proof.subscription_plan.ok_or(PublishingPlatformError::InvalidSubscription)?;
+        return subscription.ensure_grants(
+            reader,
+            &plan.key(),
+            plan,
+            &exclusive_content.author,
+            &exclusive_content.required_collection,
+            now,
+        );
+    }
+
+    let mut held: Vec<HeldChapter> = Vec::new();
+    let mut books: Vec<BookChapterCount> = Vec::new();
+
+    if let Some(chapter) = proof.chapter {
+        check_chapter_access(reader, chapter, proof.chapter_ata, proof.chapter_unlock)?;
+        add_holding(&mut held, chapter);
+    }
+
+    let mut accounts = remaining_accounts.iter();
+    while let Some(info) = accounts.next() {
+        if info.owner == &anchor_spl::token::ID {
+            let token_account = TokenAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+            let chapter = next_chapter(&mut accounts)?;
+            check_chapter_access(reader, &chapter, Some(&token_account), None)?;
+            add_holding(&mut held, &chapter);
+        } else if has_discriminator::<ChapterUnlock>(info)? {
+            let chapter_unlock = load_program_account::<ChapterUnlock>(info)?;
+            let chapter = next_chapter(&mut accounts)?;
+            check_chapter_access(reader, &chapter, None, Some(&chapter_unlock))?;
+            add_holding(&mut held, &chapter);
+        } else {
+            let book = load_program_account::<Book>(info)?;
+            books.push(BookChapterCount {
+                collection: book.collection_mint,
+                chapter_count: book.chapter_count,
+            });
+        }
+    }
+
+    require!(!held.is_empty(), PublishingPlatformError::NoNftOwnership);
+
+    exclusive_content.policy.evaluate(&held, &books)
+}
+
+// Checks that the reader either owns the chapter NFT or has paid to unlock it
+pub fn check_chapter_access(
+    reader: &Pubkey,
+    chapter: &Chapter,
+    chapter_ata: Option<&TokenAccount>,
+    chapter_unlock: Option<&ChapterUnlock>,
+) -> Result<HoldingProof> {
+    chapter.ensure_published()?;
+
+    if let Some(token_account) = chapter_ata {
+        require_keys_eq!(
+            token_account.owner,
+            *reader,
+            PublishingPlatformError::InvalidTokenOwner
+        );
+        require_keys_eq!(
+            token_account.mint,
+            chapter.chapter_mint,
+            PublishingPlatformError::InvalidTokenMint
+        );
+
+        // Verify the reader actually owns the chapter NFT
         require!(
-            self.chapter_ata.amount > 0,
+            token_account.amount > 0,
             PublishingPlatformError::NoNftOwnership
         );
-    
+        return Ok(HoldingProof::ChapterNft);
+    }
+
+    if let Some(chapter_unlock) = chapter_unlock {
         require!(
-            self.chapter.book_collection == self.exclusive_content.required_collection,
-            PublishingPlatformError::InvalidCollection
+            chapter_unlock.reader == *reader && chapter_unlock.chapter_mint == chapter.chapter_mint,
+            PublishingPlatformError::InvalidChapterUnlock
         );
-        
-        // Return the content_uri
-        Ok(self.exclusive_content.content_uri.clone()) 
+        return Ok(HoldingProof::ChapterUnlock);
     }
+
+    err!(PublishingPlatformError::NoNftOwnership)
+}
+
+fn add_holding(held: &mut Vec<HeldChapter>, chapter: &Chapter) {
+    if !held.iter().any(|c| c.mint == chapter.chapter_mint) {
+        held.push(HeldChapter {
+            mint: chapter.chapter_mint,
+            collection: chapter.book_collection,
+            chapter_number: chapter.chapter_number,
+        });
+    }
+}
+
+fn next_chapter<'a, 'info: 'a>(accounts: &mut impl Iterator<Item = &'a AccountInfo<'info>>) -> Result<Chapter> {
+    let chapter_info = accounts.next()
+        .ok_or(PublishingPlatformError::InvalidRemainingAccounts)?;
+    load_program_account::<Chapter>(chapter_info)
+}
+
+fn has_discriminator<T: Discriminator>(info: &AccountInfo) -> Result<bool> {
+    let data = info.try_borrow_data()?;
+    Ok(info.owner == &crate::ID && data.len() >= 8 && data[..8] == T::DISCRIMINATOR)
+}
+
+// Program accounts can only be created by this program at their PDAs,
+// so checking the owner and discriminator is enough to trust them
+fn load_program_account<T: AccountDeserialize>(info: &AccountInfo) -> Result<T> {
+    require_keys_eq!(
+        *info.owner,
+        crate::ID,
+        PublishingPlatformError::InvalidRemainingAccounts
+    );
+    T::try_deserialize(&mut &info.try_borrow_data()?[..])
 }
@@ -20,4 +20,148 @@ pub enum PublishingPlatformError {
     InvalidCollection,
     #[msg("Invalid rating")]
     InvalidRating,
+    #[msg("Platform fee cannot exceed 10000 basis points")]
+    InvalidFee,
+    #[msg("Only the platform admin can perform this action")]
+    UnauthorizedAdmin,
+    #[msg("Platform is paused")]
+    PlatformPaused,
+    #[msg("Mint is not a collection NFT")]
+    NotACollection,
+    #[msg("Writer is neither a verified creator nor a holder of the collection")]
+    NotCollectionOwner,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    #[msg("Chapter NFT does not belong to the book's collection")]
+    ChapterNotInCollection,
+    #[msg("Chapter NFT collection is not verified")]
+    CollectionNotVerified,
+    #[msg("Chapter mint supply must be exactly one")]
+    InvalidChapterSupply,
+    #[msg("Token account is not owned by the reader")]
+    InvalidTokenOwner,
+    #[msg("Token account mint does not match the chapter")]
+    InvalidTokenMint,
+    #[msg("Exclusive content is not active")]
+    ExclusiveContentInactive,
+    #[msg("Exclusive content is no longer available")]
+    ExclusiveContentExpired,
+    #[msg("Availability window must end in the future")]
+    InvalidAvailability,
+    #[msg("Access policy must have between 1 and 4 valid predicates")]
+    InvalidAccessPolicy,
+    #[msg("Reader does not hold enough chapters of the collection")]
+    NotEnoughChaptersHeld,
+    #[msg("Reader does not hold every chapter of the book")]
+    FullBookNotHeld,
+    #[msg("Reader does not hold the required chapter")]
+    ChapterNumberNotHeld,
+    #[msg("None of the access policy predicates are satisfied")]
+    AccessPolicyNotSatisfied,
+    #[msg("Book account required by the access policy was not provided")]
+    BookAccountMissing,
+    #[msg("Remaining accounts must be token account and chapter pairs or book accounts")]
+    InvalidRemainingAccounts,
+    #[msg("Subscription price must be greater than zero")]
+    InvalidSubscriptionPrice,
+    #[msg("Subscription period must be greater than zero")]
+    InvalidSubscriptionPeriod,
+    #[msg("Subscription plan must cover between 1 and 10 books")]
+    InvalidPlanBooks,
+    #[msg("Subscription does not belong to the reader or plan")]
+    InvalidSubscription,
+    #[msg("Subscription has expired")]
+    SubscriptionExpired,
+    #[msg("Subscription plan does not cover this content")]
+    SubscriptionNotCovering,
+    #[msg("Chapter unlock does not belong to the reader or chapter")]
+    InvalidChapterUnlock,
+    #[msg("Chapter is not for sale")]
+    ChapterNotForSale,
+    #[msg("Chapter price must be greater than zero")]
+    InvalidChapterPrice,
+    #[msg("Too many allowed tip mints")]
+    TooManyTipMints,
+    #[msg("Mint is not accepted for tips")]
+    TipMintNotAllowed,
+    #[msg("Tip message is too long")]
+    TipMessageTooLong,
+    #[msg("Tip target does not belong to the writer")]
+    InvalidTipTarget,
+    #[msg("Books need between 1 and 5 distinct contributors")]
+    InvalidContributors,
+    #[msg("Contributor shares must sum to 10000 basis points")]
+    InvalidContributorShares,
+    #[msg("Contributor wallets must be passed in the book's contributor order")]
+    ContributorAccountMismatch,
+    #[msg("Readers cannot vote on their own reviews")]
+    SelfVoteNotAllowed,
+    #[msg("Review content is too long")]
+    ReviewTooLong,
+    #[msg("Invalid reputation config")]
+    InvalidReputationConfig,
+    #[msg("Too many moderators")]
+    TooManyModerators,
+    #[msg("Signer is not a moderator")]
+    NotAModerator,
+    #[msg("Review has been hidden by a moderator")]
+    ReviewHidden,
+    #[msg("Reply content is too long")]
+    ReplyTooLong,
+    #[msg("Chapter account is required for chapter reviews")]
+    ChapterAccountMissing,
+    #[msg("Book title or genre is empty or too long")]
+    InvalidBookDetails,
+    #[msg("Royalty percentage cannot exceed 100")]
+    InvalidRoyaltyPercentage,
+    #[msg("Book still has chapters")]
+    BookHasChapters,
+    #[msg("Content URI is empty or too long")]
+    InvalidContentUri,
+    #[msg("Early access must not start after the release")]
+    InvalidReleaseSchedule,
+    #[msg("Chapter is not released yet")]
+    ChapterNotReleased,
+    #[msg("Chapter is in early access for collection holders only")]
+    EarlyAccessHoldersOnly,
+    #[msg("Chapter has already been released")]
+    ChapterAlreadyReleased,
+    #[msg("Chapter content has not been revealed yet")]
+    ChapterNotRevealed,
+    #[msg("Chapter was not created with a content commitment")]
+    ChapterNotCommitted,
+    #[msg("Chapter content has already been revealed")]
+    ChapterAlreadyRevealed,
+    #[msg("Content URI and salt do not match the commitment")]
+    InvalidReveal,
+    #[msg("Only the author can reveal a chapter before its release")]
+    RevealTooEarly,
+    #[msg("Too many editors")]
+    TooManyEditors,
+    #[msg("Signer is not an editor of this book")]
+    NotAnEditor,
+    #[msg("Chapter cannot move to that status from its current one")]
+    InvalidChapterStatus,
+    #[msg("Chapter is not published")]
+    ChapterNotPublished,
+    #[msg("Editorial note is too long")]
+    EditorialNoteTooLong,
+    #[msg("Withdraw amount must be greater than zero")]
+    InvalidWithdrawAmount,
+    #[msg("Treasury cannot go below its rent-exempt minimum")]
+    InsufficientTreasuryBalance,
+    #[msg("Writers cannot review themselves")]
+    CannotReviewSelf,
+    #[msg("Book still has exclusive content")]
+    BookHasExclusiveContent,
+    #[msg("Book writers cannot be its editors")]
+    EditorCannotWrite,
+    #[msg("Removed editors must sign to leave the book")]
+    EditorRemovalNotSigned,
+    #[msg("Book still has reviews")]
+    BookHasReviews,
+    #[msg("Access pass has expired")]
+    AccessPassExpired,
+    #[msg("Reviewer account does not belong to the review's author")]
+    ReviewerAccountMismatch,
 }
\ No newline at end of file
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::state::tip_record::TipTarget;
+
+// Emitted for every SOL tip, whether or not the tipper paid for a TipRecord
+#[event]
+pub struct TipSent {
+    pub tipper: Pubkey,
+    pub writer: Pubkey,
+    pub target: TipTarget,
+    pub amount: u64,
+    pub message: String,
+    pub index: u64,
+}
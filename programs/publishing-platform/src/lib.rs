@@ -5,17 +5,50 @@ declare_id!("8CjoRnJX56Rti4bzFjq6g2xhZK1gSmJUWKHZEDDAQqHG");
 pub mod contexts;
 pub mod state;
 pub mod errors;
+pub mod events;
 pub use contexts::*;
 pub use state::*;
 pub use errors::*;
+pub use events::*;
 
 #[program]
 pub mod publishing_platform {
 
     use super::*;   
-    pub fn initialize_platform(ctx: Context<InitializePlatform>) -> Result<()> {
-        ctx.accounts.initialize_platform()?;
-        Ok(())
+    pub fn initialize_platform(ctx: Context<InitializePlatform>, fee_bps: u16) -> Result<()> {
+        ctx.accounts.initialize_platform(fee_bps, &ctx.bumps)
+    }
+
+    pub fn update_platform_config(ctx: Context<PlatformAdmin>, fee_bps: u16) -> Result<()> {
+        ctx.accounts.update_platform_config(fee_bps)
+    }
+
+    pub fn set_allowed_tip_mints(ctx: Context<PlatformAdmin>, mints: Vec<Pubkey>) -> Result<()> {
+        ctx.accounts.set_allowed_tip_mints(mints)
+    }
+
+    pub fn set_moderators(ctx: Context<PlatformAdmin>, moderators: Vec<Pubkey>) -> Result<()> {
+        ctx.accounts.set_moderators(moderators)
+    }
+
+    pub fn set_reputation_config(ctx: Context<PlatformAdmin>, config: ReputationConfig) -> Result<()> {
+        ctx.accounts.set_reputation_config(config)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_treasury(amount)
+    }
+
+    pub fn transfer_admin(ctx: Context<PlatformAdmin>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.transfer_admin(new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        ctx.accounts.accept_admin()
+    }
+
+    pub fn set_paused(ctx: Context<PlatformAdmin>, paused: bool) -> Result<()> {
+        ctx.accounts.set_paused(paused)
     }
     
     pub fn create_writer_account(ctx: Context<CreateWriterAccount>) -> Result<()> {
@@ -26,28 +59,188 @@ pub mod publishing_platform {
         ctx.accounts.create_reader_account(&ctx.bumps)
     }   
 
-    pub fn tip_writer(ctx: Context<TipWriter>, amount : u64) -> Result<()> {
-        ctx.accounts.tip_writer(amount)
+    pub fn tip_writer<'info>(ctx: Context<'_, '_, '_, 'info, TipWriter<'info>>, amount : u64, message: String) -> Result<()> {
+        ctx.accounts.tip_writer(amount, message, ctx.remaining_accounts, &ctx.bumps)
+    }
+
+    pub fn tip_writer_token(ctx: Context<TipWriterToken>, amount: u64) -> Result<()> {
+        ctx.accounts.tip_writer_token(amount)
     }
 
     pub fn create_book(ctx: Context<CreateBook>, title: String, royalties: u8, genre: String) -> Result<()> {
         ctx.accounts.create_book(title, royalties, genre)
     }
 
-    pub fn add_chapter(ctx: Context<AddChapter>, title: String, content_uri: String) -> Result<()> {
-        ctx.accounts.add_chapter(title, content_uri)
+    pub fn update_book(ctx: Context<UpdateBook>, title: String, genre: String, royalty_percentage: u8) -> Result<()> {
+        ctx.accounts.update_book(title, genre, royalty_percentage)
+    }
+
+    pub fn set_book_editors(ctx: Context<UpdateBook>, editors: Vec<Pubkey>) -> Result<()> {
+        ctx.accounts.set_book_editors(editors, ctx.remaining_accounts)
+    }
+
+    pub fn set_book_status(ctx: Context<UpdateBook>, status: BookStatus) -> Result<()> {
+        ctx.accounts.set_book_status(status)
+    }
+
+    pub fn close_book(ctx: Context<CloseBook>) -> Result<()> {
+        ctx.accounts.close_book()
+    }
+
+    pub fn set_book_contributors(ctx: Context<UpdateBook>, contributors: Vec<Contributor>) -> Result<()> {
+        ctx.accounts.set_book_contributors(contributors)
+    }
+
+    pub fn add_chapter(ctx: Context<AddChapter>, title: String, content: ChapterContent, content_hash: [u8; 32], price_lamports: Option<u64>, schedule: Option<ReleaseSchedule>) -> Result<()> {
+        ctx.accounts.add_chapter(title, content, content_hash, price_lamports, schedule)
+    }
+
+    pub fn submit_for_review(ctx: Context<ChapterWorkflow>) -> Result<()> {
+        ctx.accounts.submit_for_review()
+    }
+
+    pub fn withdraw_chapter(ctx: Context<ChapterWorkflow>) -> Result<()> {
+        ctx.accounts.withdraw_chapter()
+    }
+
+    pub fn publish_chapter(ctx: Context<ChapterWorkflow>) -> Result<()> {
+        ctx.accounts.publish_chapter()
+    }
+
+    pub fn approve_chapter(ctx: Context<EditorDecision>, note: String) -> Result<()> {
+        ctx.accounts.approve_chapter(note)
+    }
+
+    pub fn reject_chapter(ctx: Context<EditorDecision>, note: String) -> Result<()> {
+        ctx.accounts.reject_chapter(note)
+    }
+
+    pub fn reveal_chapter(ctx: Context<RevealChapter>, content_uri: String, salt: [u8; 32]) -> Result<()> {
+        ctx.accounts.reveal_chapter(content_uri, salt)
+    }
+
+    pub fn set_release_schedule(ctx: Context<UpdateChapter>, schedule: ReleaseSchedule) -> Result<()> {
+        ctx.accounts.set_release_schedule(schedule)
+    }
+
+    pub fn read_chapter(ctx: Context<ReadChapter>) -> Result<String> {
+        ctx.accounts.read_chapter()
+    }
+
+    pub fn revise_chapter(ctx: Context<ReviseChapter>, content_uri: String, content_hash: [u8; 32]) -> Result<()> {
+        ctx.accounts.revise_chapter(content_uri, content_hash, &ctx.bumps)
+    }
+
+    pub fn set_chapter_price(ctx: Context<UpdateChapter>, price_lamports: Option<u64>) -> Result<()> {
+        ctx.accounts.set_chapter_price(price_lamports)
     }
 
-    pub fn create_exclusive_content(ctx: Context<CreateExclusiveContent>, content_uri: String) -> Result<()> {
-        ctx.accounts.create_exclusive_content(content_uri)
+    pub fn unlock_chapter<'info>(ctx: Context<'_, '_, '_, 'info, UnlockChapter<'info>>) -> Result<()> {
+        ctx.accounts.unlock_chapter(ctx.remaining_accounts, &ctx.bumps)
+    }
+
+    pub fn create_exclusive_content(ctx: Context<CreateExclusiveContent>, content_uri: String, available_until: Option<i64>, policy: Option<AccessPolicy>) -> Result<()> {
+        ctx.accounts.create_exclusive_content(content_uri, available_until, policy, &ctx.bumps)
+    }
+
+    pub fn update_exclusive_content(ctx: Context<ManageExclusiveContent>, content_uri: String, available_until: Option<i64>) -> Result<()> {
+        ctx.accounts.update_exclusive_content(content_uri, available_until)
+    }
+
+    pub fn set_access_policy(ctx: Context<ManageExclusiveContent>, policy: AccessPolicy) -> Result<()> {
+        ctx.accounts.set_access_policy(policy)
+    }
+
+    pub fn deactivate_exclusive_content(ctx: Context<ManageExclusiveContent>) -> Result<()> {
+        ctx.accounts.deactivate_exclusive_content()
+    }
+
+    pub fn close_exclusive_content(ctx: Context<CloseExclusiveContent>) -> Result<()> {
+        ctx.accounts.close_exclusive_content()
     }
 
     pub fn verify_access(ctx: Context<VerifyAccess>) -> Result<String> {
-        ctx.accounts.verify_access()
+        ctx.accounts.verify_access(ctx.remaining_accounts)
+    }
+
+    pub fn grant_access_pass(ctx: Context<GrantAccessPass>) -> Result<()> {
+        ctx.accounts.grant_access_pass(ctx.remaining_accounts, &ctx.bumps)
+    }
+
+    pub fn check_access_pass(ctx: Context<CheckAccessPass>) -> Result<String> {
+        ctx.accounts.check_access_pass()
     }
 
     pub fn submit_review(ctx: Context<SubmitReview>, content: String, rating: u8) -> Result<()> {
-        ctx.accounts.submit_review(content, rating)
+        ctx.accounts.submit_review(content, rating, &ctx.bumps)
+    }
+
+    pub fn submit_book_review(ctx: Context<SubmitBookReview>, content: String, rating: u8) -> Result<()> {
+        ctx.accounts.submit_book_review(content, rating, &ctx.bumps)
+    }
+
+    pub fn submit_writer_review(ctx: Context<SubmitWriterReview>, content: String, rating: u8) -> Result<()> {
+        ctx.accounts.submit_writer_review(content, rating, &ctx.bumps)
+    }
+
+    pub fn edit_review(ctx: Context<EditReview>, content: String, rating: u8) -> Result<()> {
+        ctx.accounts.edit_review(content, rating)
+    }
+
+    pub fn delete_review(ctx: Context<DeleteReview>) -> Result<()> {
+        ctx.accounts.delete_review()
+    }
+
+    pub fn reply_to_review(ctx: Context<ReplyToReview>, content: String) -> Result<()> {
+        ctx.accounts.reply_to_review(content, &ctx.bumps)
+    }
+
+    pub fn edit_review_reply(ctx: Context<EditReviewReply>, content: String) -> Result<()> {
+        ctx.accounts.edit_review_reply(content)
+    }
+
+    pub fn delete_review_reply(ctx: Context<DeleteReviewReply>) -> Result<()> {
+        ctx.accounts.delete_review_reply()
+    }
+
+    pub fn flag_review(ctx: Context<FlagReview>) -> Result<()> {
+        ctx.accounts.flag_review(&ctx.bumps)
+    }
+
+    pub fn unflag_review(ctx: Context<UnflagReview>) -> Result<()> {
+        ctx.accounts.unflag_review()
+    }
+
+    pub fn hide_review(ctx: Context<HideReview>) -> Result<()> {
+        ctx.accounts.hide_review()
+    }
+
+    pub fn remove_review(ctx: Context<RemoveReview>) -> Result<()> {
+        ctx.accounts.remove_review()
+    }
+
+    pub fn recompute_reputation(ctx: Context<RecomputeReputation>) -> Result<()> {
+        ctx.accounts.recompute_reputation()
+    }
+
+    pub fn upvote_review(ctx: Context<UpvoteReview>) -> Result<()> {
+        ctx.accounts.upvote_review(&ctx.bumps)
+    }
+
+    pub fn remove_upvote(ctx: Context<RemoveUpvote>) -> Result<()> {
+        ctx.accounts.remove_upvote()
+    }
+
+    pub fn create_subscription_plan(ctx: Context<CreateSubscriptionPlan>, price_per_period: u64, period_seconds: i64, books: Vec<Pubkey>) -> Result<()> {
+        ctx.accounts.create_subscription_plan(price_per_period, period_seconds, books, &ctx.bumps)
+    }
+
+    pub fn subscribe(ctx: Context<Subscribe>, periods: u32) -> Result<()> {
+        ctx.accounts.subscribe(periods, &ctx.bumps)
+    }
+
+    pub fn renew(ctx: Context<Renew>, periods: u32) -> Result<()> {
+        ctx.accounts.renew(periods)
     }
 
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::state::content::ExclusiveContent;
+use crate::errors::PublishingPlatformError;
+
+pub const ACCESS_PASS_VALIDITY_SECONDS: i64 = 24 * 60 * 60;
+
+#[account]
+#[derive(InitSpace)]
+pub struct AccessPass {
+    pub reader: Pubkey,
+    pub exclusive_content: Pubkey,
+    pub granted_at: i64,
+    pub expires_at: i64,            // Capped by the content's availability and the subscription that granted it
+    pub bump: u8,
+}
+
+impl AccessPass {
+    // A pass only lasts as long as its content, so deactivating or closing
+    // the content ends every pass granted for it
+    pub fn ensure_valid(&self, exclusive_content: &ExclusiveContent, now: i64) -> Result<()> {
+        exclusive_content.ensure_available(now)?;
+        require!(now < self.expires_at, PublishingPlatformError::AccessPassExpired);
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PublishingPlatformError;
+
+pub const MAX_ACCESS_PREDICATES: usize = 4;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum PolicyMode {
+    All, // Every predicate must hold
+    Any, // At least one predicate must hold
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub enum AccessPredicate {
+    HoldsChapters { collection: Pubkey, min_count: u8 },
+    HoldsFullBook { collection: Pubkey },
+    HoldsChapterNumber { collection: Pubkey, chapter_number: u8 },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub struct AccessPolicy {
+    pub mode: PolicyMode,
+    #[max_len(MAX_ACCESS_PREDICATES)]
+    pub predicates: Vec<AccessPredicate>,
+}
+
+// How a reader proved access to a single chapter
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum HoldingProof {
+    ChapterNft,
+    ChapterUnlock,
+    Subscription,
+}
+
+impl HoldingProof {
+    // NFT holders and paid unlocks own the chapter, subscribers only borrow it
+    pub fn is_purchase(&self) -> bool {
+        matches!(self, HoldingProof::ChapterNft | HoldingProof::ChapterUnlock)
+    }
+}
+
+// A chapter NFT the reader has proven to hold
+pub struct HeldChapter {
+    pub mint: Pubkey,
+    pub collection: Pubkey,
+    pub chapter_number: u8,
+}
+
+// Chapter count of a book referenced by a HoldsFullBook predicate
+pub struct BookChapterCount {
+    pub collection: Pubkey,
+    pub chapter_count: u8,
+}
+
+impl AccessPolicy {
+    // Equivalent to the original rule: hold any chapter of the collection
+    pub fn holds_collection(collection: Pubkey) -> Self {
+        AccessPolicy {
+            mode: PolicyMode::All,
+            predicates: vec![AccessPredicate::HoldsChapters { collection, min_count: 1 }],
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.predicates.is_empty() && self.predicates.len() <= MAX_ACCESS_PREDICATES,
+            PublishingPlatformError::InvalidAccessPolicy
+        );
+        for predicate in self.predicates.iter() {
+            if let AccessPredicate::HoldsChapters { min_count, .. } = predicate {
+                require!(*min_count > 0, PublishingPlatformError::InvalidAccessPolicy);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn evaluate(&self, held: &[HeldChapter], books: &[BookChapterCount]) -> Result<()> {
+        match self.mode {
+            PolicyMode::All => {
+                for (i, predicate) in self.predicates.iter().enumerate() {
+                    if let Err(err) = predicate.evaluate(held, books) {
+                        msg!("Access predicate {} failed", i);
+                        return Err(err);
+                    }
+                }
+                Ok(())
+            }
+            PolicyMode::Any => {
+                for (i, predicate) in self.predicates.iter().enumerate() {
+                    match predicate.evaluate(held, books) {
+                        Ok(()) => return Ok(()),
+                        Err(err) => msg!("Access predicate {} failed: {}", i, err),
+                    }
+                }
+                err!(PublishingPlatformError::AccessPolicyNotSatisfied)
+            }
+        }
+    }
+}
+
+impl AccessPredicate {
+    pub fn evaluate(&self, held: &[HeldChapter], books: &[BookChapterCount]) -> Result<()> {
+        match self {
+            AccessPredicate::HoldsChapters { collection, min_count } => {
+                require!(
+                    count_held(held, collection) >= *min_count as usize,
+                    PublishingPlatformError::NotEnoughChaptersHeld
+                );
+            }
+            AccessPredicate::HoldsFullBook { collection } => {
+                let book = books
+                    .iter()
+                    .find(|b| b.collection == *collection)
+                    .ok_or(PublishingPlatformError::BookAccountMissing)?;
+                require!(
+                    book.chapter_count > 0 && count_held(held, collection) >= book.chapter_count as usize,
+                    PublishingPlatformError::FullBookNotHeld
+                );
+            }
+            AccessPredicate::HoldsChapterNumber { collection, chapter_number } => {
+                require!(
+                    held.iter().any(|c| c.collection == *collection && c.chapter_number == *chapter_number),
+                    PublishingPlatformError::ChapterNumberNotHeld
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+// Holdings are de-duplicated by mint before evaluation, so this counts distinct chapters
+fn count_held(held: &[HeldChapter], collection: &Pubkey) -> usize {
+    held.iter().filter(|c| c.collection == *collection).count()
+}
@@ -1,7 +0,0 @@
-use anchor_lang::prelude::*;
-
-#[account]
-#[derive(InitSpace)]
-pub struct Admin {
-    pub bump: u8,
-}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::state::content::MAX_CONTENT_URI_LEN;
+
+// Snapshot of a chapter version, written when revise_chapter replaces it
+#[account]
+#[derive(InitSpace)]
+pub struct ChapterRevision {
+    pub chapter: Pubkey,
+    pub revision: u32,
+    #[max_len(MAX_CONTENT_URI_LEN)]
+    pub content_uri: String,
+    pub content_hash: [u8; 32],
+    pub replaced_at: i64,
+    pub bump: u8,
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ChapterUnlock {
+    pub reader: Pubkey,
+    pub chapter: Pubkey,
+    pub chapter_mint: Pubkey,
+    pub price_paid: u64,
+    pub unlocked_at: i64,
+    pub bump: u8,
+}
@@ -1,34 +1,307 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::hash::hashv, system_program::{Transfer, transfer}};
+
+use crate::errors::PublishingPlatformError;
+use crate::state::access_policy::AccessPolicy;
+use crate::state::rating::RatingAggregate;
+use crate::state::user_account::WriterAccount;
+
+pub const MAX_CONTRIBUTORS: usize = 5;
+pub const TOTAL_SHARE_BPS: u16 = 10_000;
+pub const MAX_BOOK_TITLE_LEN: usize = 50;
+pub const MAX_GENRE_LEN: usize = 20;
+pub const MAX_ROYALTY_PERCENTAGE: u8 = 100;
+pub const MAX_CONTENT_URI_LEN: usize = 100;
+pub const MAX_EDITORS: usize = 5;
+pub const MAX_EDITORIAL_NOTE_LEN: usize = 200;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum BookStatus {
+    Draft,
+    Ongoing,
+    Completed,
+    Hiatus,
+    Discontinued,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum ContributorRole {
+    Author,
+    CoAuthor,
+    Editor,
+    Illustrator,
+    Translator,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub struct Contributor {
+    pub wallet: Pubkey,
+    pub role: ContributorRole,
+    pub share_bps: u16,             // Share of book revenue, all shares sum to 10000
+    pub can_write: bool,            // Allowed to add chapters
+}
 
 #[account]
 #[derive(InitSpace)]
 pub struct Chapter {
     #[max_len(50)]      
     pub title: String,
-    #[max_len(100)]
+    #[max_len(MAX_CONTENT_URI_LEN)]
     pub content_uri: String,
     pub author: Pubkey,
     pub book_collection: Pubkey,
     pub chapter_number: u8,
     pub is_exclusive: bool,
     pub review_count: u32,
-    pub rating: u8,
+    pub rating: RatingAggregate,
     pub chapter_mint: Pubkey,
+    pub price_lamports: Option<u64>, // Pay-per-chapter unlock price, not for sale when None
+    pub content_hash: [u8; 32],      // SHA-256 of the canonical chapter text behind content_uri
+    pub revision: u32,               // Number of times the chapter has been revised
+    pub early_access_at: i64,        // Collection holders can read from here on
+    pub release_at: i64,             // Everyone can read from here on
+    pub uri_commitment: Option<[u8; 32]>, // SHA-256 of content_uri and a salt, for chapters revealed later
+    pub revealed_at: Option<i64>,
+    pub status: ChapterStatus,
+    pub reviewed_by: Option<Pubkey>,      // Editor behind the last approval or rejection
+    #[max_len(MAX_EDITORIAL_NOTE_LEN)]
+    pub editorial_note: String,
+}
+
+// Draft -> Submitted -> Approved / Rejected -> Published. Books without
+// editors skip the review steps and go from Draft straight to Published.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum ChapterStatus {
+    Draft,
+    Submitted,
+    Approved,
+    Rejected,
+    Published,
+}
+
+// How add_chapter receives the chapter's location
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum ChapterContent {
+    Public { content_uri: String },
+    // content_uri stays empty until reveal_chapter is given the preimage
+    Committed { uri_commitment: [u8; 32] },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct ReleaseSchedule {
+    pub early_access_at: i64,
+    pub release_at: i64,
+}
+
+impl ReleaseSchedule {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.early_access_at <= self.release_at,
+            PublishingPlatformError::InvalidReleaseSchedule
+        );
+        Ok(())
+    }
+}
+
+impl Chapter {
+    pub fn ensure_published(&self) -> Result<()> {
+        require!(
+            self.status == ChapterStatus::Published,
+            PublishingPlatformError::ChapterNotPublished
+        );
+        Ok(())
+    }
+
+    pub fn transition(&mut self, from: &[ChapterStatus], to: ChapterStatus) -> Result<()> {
+        require!(from.contains(&self.status), PublishingPlatformError::InvalidChapterStatus);
+        self.status = to;
+        Ok(())
+    }
+
+    // Hidden before early access, holders only until release, public afterwards
+    pub fn ensure_readable(&self, now: i64, is_collection_holder: bool) -> Result<()> {
+        self.ensure_published()?;
+        require!(self.is_revealed(), PublishingPlatformError::ChapterNotRevealed);
+        require!(now >= self.early_access_at, PublishingPlatformError::ChapterNotReleased);
+        require!(
+            now >= self.release_at || is_collection_holder,
+            PublishingPlatformError::EarlyAccessHoldersOnly
+        );
+        Ok(())
+    }
+
+    pub fn is_revealed(&self) -> bool {
+        self.uri_commitment.is_none() || self.revealed_at.is_some()
+    }
+
+    pub fn commitment_for(content_uri: &str, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[content_uri.as_bytes(), salt]).to_bytes()
+    }
+
+    pub fn reveal(&mut self, content_uri: String, salt: [u8; 32], now: i64) -> Result<()> {
+        let commitment = self.uri_commitment.ok_or(PublishingPlatformError::ChapterNotCommitted)?;
+        require!(self.revealed_at.is_none(), PublishingPlatformError::ChapterAlreadyRevealed);
+        require!(
+            Self::commitment_for(&content_uri, &salt) == commitment,
+            PublishingPlatformError::InvalidReveal
+        );
+        Self::validate_content_uri(&content_uri)?;
+
+        self.content_uri = content_uri;
+        self.revealed_at = Some(now);
+        Ok(())
+    }
+
+    pub fn is_released(&self, now: i64) -> bool {
+        now >= self.release_at
+    }
+
+    pub fn validate_content_uri(content_uri: &str) -> Result<()> {
+        require!(
+            !content_uri.is_empty() && content_uri.len() <= MAX_CONTENT_URI_LEN,
+            PublishingPlatformError::InvalidContentUri
+        );
+        Ok(())
+    }
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct Book {
-    #[max_len(50)]
+    #[max_len(MAX_BOOK_TITLE_LEN)]
     pub title: String,
     pub author: Pubkey,
     pub chapter_count: u8,
-    #[max_len(20)]
+    #[max_len(MAX_GENRE_LEN)]
     pub genre: String,
     pub royalty_percentage: u8,
     pub total_sales: u64,
-    pub review_score: u8,
+    pub chapter_rating: RatingAggregate, // Rolled up from every review of the book's chapters
+    pub book_rating: RatingAggregate,    // Reviews of the book as a whole
+    pub status: BookStatus,
     pub collection_mint: Pubkey,
+    pub exclusive_content_count: u32, // Next index used to derive ExclusiveContent PDAs
+    pub live_exclusive_content: u32,  // ExclusiveContent accounts not closed yet, the book cannot close until 0
+    pub total_tips: u64,
+    #[max_len(MAX_CONTRIBUTORS)]
+    pub contributors: Vec<Contributor>,
+    #[max_len(MAX_EDITORS)]
+    pub editors: Vec<Pubkey>,            // Must approve chapters before they can be published
+}
+
+impl Book {
+    pub fn validate_details(title: &str, genre: &str, royalty_percentage: u8) -> Result<()> {
+        require!(
+            !title.is_empty() && title.len() <= MAX_BOOK_TITLE_LEN && genre.len() <= MAX_GENRE_LEN,
+            PublishingPlatformError::InvalidBookDetails
+        );
+        require!(
+            royalty_percentage <= MAX_ROYALTY_PERCENTAGE,
+            PublishingPlatformError::InvalidRoyaltyPercentage
+        );
+        Ok(())
+    }
+
+    pub fn validate_contributors(contributors: &[Contributor]) -> Result<()> {
+        require!(
+            !contributors.is_empty() && contributors.len() <= MAX_CONTRIBUTORS,
+            PublishingPlatformError::InvalidContributors
+        );
+        for (i, contributor) in contributors.iter().enumerate() {
+            require!(
+                !contributors[..i].iter().any(|c| c.wallet == contributor.wallet),
+                PublishingPlatformError::InvalidContributors
+            );
+        }
+
+        let total: u32 = contributors.iter().map(|c| c.share_bps as u32).sum();
+        require!(
+            total == TOTAL_SHARE_BPS as u32,
+            PublishingPlatformError::InvalidContributorShares
+        );
+        Ok(())
+    }
+
+    pub fn contributor(&self, wallet: &Pubkey) -> Option<&Contributor> {
+        self.contributors.iter().find(|c| c.wallet == *wallet)
+    }
+
+    pub fn can_write(&self, wallet: &Pubkey) -> bool {
+        self.contributor(wallet).is_some_and(|c| c.can_write)
+    }
+
+    // Splits `amount` by contributor share. Rounding dust goes to the first contributor.
+    pub fn split(&self, amount: u64) -> Result<Vec<u64>> {
+        let mut shares = Vec::with_capacity(self.contributors.len());
+        for contributor in self.contributors.iter() {
+            let share = (amount as u128)
+                .checked_mul(contributor.share_bps as u128)
+                .map(|x| x / TOTAL_SHARE_BPS as u128)
+                .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+            shares.push(share as u64);
+        }
+
+        let distributed: u64 = shares.iter().sum();
+        if let Some(first) = shares.first_mut() {
+            *first += amount - distributed;
+        }
+        Ok(shares)
+    }
+
+    // Pays `amount` from the payer to every contributor. `recipients` must be the
+    // contributors' wallets in the same order as `contributors`, each optionally
+    // followed by that contributor's WriterAccount, which `credit` then updates
+    // with their share. Returns the share paid to `wallet`, whose WriterAccount
+    // the caller already holds and updates itself.
+    pub fn distribute<'info>(
+        &self,
+        amount: u64,
+        wallet: &Pubkey,
+        system_program: AccountInfo<'info>,
+        payer: AccountInfo<'info>,
+        recipients: &[AccountInfo<'info>],
+        mut credit: impl FnMut(&mut WriterAccount, u64) -> Result<()>,
+    ) -> Result<u64> {
+        let mut paid_to_wallet = 0;
+        let shares = self.split(amount)?;
+        let mut accounts = recipients.iter().peekable();
+        for (contributor, share) in self.contributors.iter().zip(shares) {
+            let recipient = accounts.next()
+                .ok_or(PublishingPlatformError::ContributorAccountMismatch)?;
+            require_keys_eq!(
+                recipient.key(),
+                contributor.wallet,
+                PublishingPlatformError::ContributorAccountMismatch
+            );
+            // Wallets are never owned by this program, so anything that is must be a WriterAccount
+            let writer_account = accounts.next_if(|info| info.owner == &crate::ID);
+
+            if contributor.wallet == *wallet {
+                paid_to_wallet = share;
+            } else if let Some(info) = writer_account {
+                let mut account = WriterAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+                require_keys_eq!(
+                    account.wallet_address,
+                    contributor.wallet,
+                    PublishingPlatformError::ContributorAccountMismatch
+                );
+                credit(&mut account, share)?;
+                account.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+            }
+            if share == 0 {
+                continue;
+            }
+
+            let cpi_ctx = CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: recipient.clone(),
+                },
+            );
+            transfer(cpi_ctx, share)?;
+        }
+        Ok(paid_to_wallet)
+    }
 }
 
 #[account]
@@ -40,4 +313,18 @@ pub struct ExclusiveContent {
     pub content_uri: String,          // IPFS/Arweave URI for the exclusive content
     pub is_active: bool,
     pub created_at: i64,
+    pub index: u32,
+    pub available_until: Option<i64>, // No expiry when None
+    pub policy: AccessPolicy,
+    pub bump: u8,
+}
+
+impl ExclusiveContent {
+    pub fn ensure_available(&self, now: i64) -> Result<()> {
+        require!(self.is_active, PublishingPlatformError::ExclusiveContentInactive);
+        if let Some(available_until) = self.available_until {
+            require!(now <= available_until, PublishingPlatformError::ExclusiveContentExpired);
+        }
+        Ok(())
+    }
 }
\ No newline at end of file
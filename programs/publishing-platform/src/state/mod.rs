@@ -1,10 +1,24 @@
 pub mod user_account;
 pub mod platform_account;
-pub mod admin;        
 pub mod content;
 pub mod review;
+pub mod rating;
+pub mod reputation;
+pub mod access_pass;
+pub mod access_policy;
+pub mod subscription;
+pub mod chapter_unlock;
+pub mod chapter_revision;
+pub mod tip_record;
 pub use user_account::*;
 pub use platform_account::*;
-pub use admin::*;
 pub use content::*;
-pub use review::*;  
\ No newline at end of file
+pub use review::*;
+pub use rating::*;
+pub use reputation::*;
+pub use access_pass::*;
+pub use access_policy::*;
+pub use subscription::*;
+pub use chapter_unlock::*;
+pub use chapter_revision::*;
+pub use tip_record::*;  
@@ -1,7 +1,86 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, system_program::{Transfer, transfer}};
+
+use crate::errors::PublishingPlatformError;
+use crate::state::reputation::ReputationConfig;
+
+pub const MAX_FEE_BPS: u16 = 10_000;
+pub const MAX_TIP_MINTS: usize = 5;
+pub const MAX_MODERATORS: usize = 10;
 
 #[account]
 #[derive(InitSpace)]
 pub struct PlatformAccount {
-    pub counter: u32,
-}
\ No newline at end of file
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>, // Set by transfer_admin, cleared by accept_admin
+    pub fee_bps: u16,
+    pub treasury: Pubkey,
+    pub paused: bool,
+    pub bump: u8,
+    pub treasury_bump: u8,
+    #[max_len(MAX_TIP_MINTS)]
+    pub allowed_tip_mints: Vec<Pubkey>, // SPL / Token-2022 mints accepted by tip_writer_token
+    pub reputation: ReputationConfig,
+    #[max_len(MAX_MODERATORS)]
+    pub moderators: Vec<Pubkey>,        // Allowed to hide and remove reviews, alongside the admin
+}
+
+impl PlatformAccount {
+    pub fn is_moderator(&self, key: &Pubkey) -> bool {
+        self.admin == *key || self.moderators.contains(key)
+    }
+
+    pub fn fee_for(&self, amount: u64) -> Result<u64> {
+        let fee = (amount as u128)
+            .checked_mul(self.fee_bps as u128)
+            .map(|x| x / MAX_FEE_BPS as u128)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        Ok(fee as u64)
+    }
+
+    // Moves `amount` lamports from the payer, sending the platform fee to the treasury
+    // and the rest to the recipient. Returns what the recipient received.
+    pub fn collect_payment<'info>(
+        &self,
+        amount: u64,
+        system_program: AccountInfo<'info>,
+        payer: AccountInfo<'info>,
+        recipient: AccountInfo<'info>,
+        treasury: AccountInfo<'info>,
+    ) -> Result<u64> {
+        let net = self.collect_fee(amount, system_program.clone(), payer.clone(), treasury)?;
+
+        let cpi_ctx = CpiContext::new(
+            system_program,
+            Transfer {
+                from: payer,
+                to: recipient,
+            },
+        );
+        transfer(cpi_ctx, net)?;
+        Ok(net)
+    }
+
+    // Sends only the platform fee on `amount` to the treasury and returns what is
+    // left for the caller to pay out
+    pub fn collect_fee<'info>(
+        &self,
+        amount: u64,
+        system_program: AccountInfo<'info>,
+        payer: AccountInfo<'info>,
+        treasury: AccountInfo<'info>,
+    ) -> Result<u64> {
+        let fee = self.fee_for(amount)?;
+
+        if fee > 0 {
+            let cpi_ctx = CpiContext::new(
+                system_program,
+                Transfer {
+                    from: payer,
+                    to: treasury,
+                },
+            );
+            transfer(cpi_ctx, fee)?;
+        }
+        Ok(amount - fee)
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PublishingPlatformError;
+
+// Averages are stored as fixed point with two decimals, 425 = 4.25 stars
+pub const RATING_SCALE: u64 = 100;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, InitSpace)]
+pub struct RatingAggregate {
+    pub rating_sum: u64,
+    pub rating_count: u32,
+    pub average: u16,
+    pub weighted_sum: u64,  // Sum of rating * reviewer weight
+    pub weight_total: u64,
+    pub weighted_average: u16,
+}
+
+impl RatingAggregate {
+    pub fn add(&mut self, rating: u8, weight: u64) -> Result<()> {
+        let weighted = (rating as u64).checked_mul(weight)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+
+        self.rating_sum = self.rating_sum.checked_add(rating as u64)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.rating_count = self.rating_count.checked_add(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.weighted_sum = self.weighted_sum.checked_add(weighted)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.weight_total = self.weight_total.checked_add(weight)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.update_averages()
+    }
+
+    // `weight` must be the one the rating was added with
+    pub fn remove(&mut self, rating: u8, weight: u64) -> Result<()> {
+        let weighted = (rating as u64).checked_mul(weight)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+
+        self.rating_sum = self.rating_sum.checked_sub(rating as u64)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.rating_count = self.rating_count.checked_sub(1)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.weighted_sum = self.weighted_sum.checked_sub(weighted)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.weight_total = self.weight_total.checked_sub(weight)
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        self.update_averages()
+    }
+
+    fn update_averages(&mut self) -> Result<()> {
+        self.average = scaled_average(self.rating_sum, self.rating_count as u64)?;
+        self.weighted_average = scaled_average(self.weighted_sum, self.weight_total)?;
+        Ok(())
+    }
+}
+
+fn scaled_average(sum: u64, count: u64) -> Result<u16> {
+    if count == 0 {
+        return Ok(0);
+    }
+    let average = (sum as u128 * RATING_SCALE as u128) / count as u128;
+    Ok(u16::try_from(average).map_err(|_| PublishingPlatformError::ArithmeticOverflow)?)
+}
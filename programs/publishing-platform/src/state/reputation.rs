@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PublishingPlatformError;
+
+pub const MAX_DECAY_BPS: u16 = 10_000;
+// Fixed-point scale of the decay factor, 1.0 = DECAY_SCALE
+const DECAY_SCALE: u128 = 1_000_000_000_000;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub struct ReputationConfig {
+    pub review_weight: u32,          // Points for writing a review
+    pub verified_review_weight: u32, // Extra points when the reviewer owns the chapter NFT or an unlock
+    pub upvote_weight: u32,          // Points for each upvote received
+    pub penalty_weight: u32,         // Points removed for each moderation penalty
+    pub decay_bps: u16,              // Share of reputation lost every decay period
+    pub decay_period_seconds: i64,
+    pub rating_weight_floor: u64,    // Weight a review carries in weighted ratings, whatever the reviewer's reputation
+    pub rating_weight_cap: u64,
+}
+
+impl ReputationConfig {
+    pub const DEFAULT: Self = Self {
+        review_weight: 10,
+        verified_review_weight: 15,
+        upvote_weight: 5,
+        penalty_weight: 100,
+        decay_bps: 500,
+        decay_period_seconds: 30 * 24 * 60 * 60,
+        rating_weight_floor: 10,
+        rating_weight_cap: 500,
+    };
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.decay_bps <= MAX_DECAY_BPS && self.decay_period_seconds > 0,
+            PublishingPlatformError::InvalidReputationConfig
+        );
+        require!(
+            self.rating_weight_floor > 0 && self.rating_weight_floor <= self.rating_weight_cap,
+            PublishingPlatformError::InvalidReputationConfig
+        );
+        Ok(())
+    }
+
+    // Points a review earns its author, verified purchases earn the bonus on top
+    pub fn review_reward(&self, verified_holder: bool) -> u64 {
+        let bonus = if verified_holder { self.verified_review_weight } else { 0 };
+        self.review_weight as u64 + bonus as u64
+    }
+
+    pub fn rating_weight(&self, reputation_score: u64) -> u64 {
+        reputation_score.clamp(self.rating_weight_floor, self.rating_weight_cap)
+    }
+
+    // Decays `score` over the periods elapsed since `since`. Returns the new score and
+    // the timestamp it is now valid from, leftover time counts towards the next period.
+    pub fn decay(&self, score: u64, since: i64, now: i64) -> Result<(u64, i64)> {
+        let periods = now.saturating_sub(since) / self.decay_period_seconds;
+        if periods <= 0 {
+            return Ok((score, since));
+        }
+
+        // Raise the per-period factor to the number of periods by squaring, so every
+        // elapsed period applies in a bounded number of steps
+        let mut factor = (MAX_DECAY_BPS - self.decay_bps) as u128 * DECAY_SCALE / MAX_DECAY_BPS as u128;
+        let mut remaining = periods as u64;
+        let mut retained = DECAY_SCALE;
+        while remaining > 0 && retained > 0 {
+            if remaining & 1 == 1 {
+                retained = retained * factor / DECAY_SCALE;
+            }
+            factor = factor * factor / DECAY_SCALE;
+            remaining >>= 1;
+        }
+        let decayed = score as u128 * retained / DECAY_SCALE;
+
+        let elapsed = periods
+            .checked_mul(self.decay_period_seconds)
+            .and_then(|x| since.checked_add(x))
+            .ok_or(PublishingPlatformError::ArithmeticOverflow)?;
+        Ok((decayed as u64, elapsed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PERIOD: i64 = ReputationConfig::DEFAULT.decay_period_seconds;
+
+    fn config(decay_bps: u16) -> ReputationConfig {
+        ReputationConfig { decay_bps, ..ReputationConfig::DEFAULT }
+    }
+
+    #[test]
+    fn decay_applies_every_elapsed_period() {
+        let (score, updated_at) = config(500).decay(1_000, 0, PERIOD).unwrap();
+        assert_eq!(score, 950);
+        assert_eq!(updated_at, PERIOD);
+
+        let (score, _) = config(500).decay(1_000_000_000, 0, 200 * PERIOD).unwrap();
+        let expected = 1_000_000_000f64 * 0.95f64.powi(200);
+        assert!((score as f64 - expected).abs() <= expected * 1e-6 + 1.0);
+        // Well below what stopping after 120 periods would leave
+        assert!((score as f64) < 1_000_000_000f64 * 0.95f64.powi(120));
+
+        let (score, _) = config(500).decay(u64::MAX, 0, 10_000 * PERIOD).unwrap();
+        assert_eq!(score, 0);
+    }
+
+    #[test]
+    fn full_decay_clears_the_score() {
+        let (score, updated_at) = config(MAX_DECAY_BPS).decay(12_345, 0, 3 * PERIOD).unwrap();
+        assert_eq!(score, 0);
+        assert_eq!(updated_at, 3 * PERIOD);
+    }
+
+    #[test]
+    fn zero_decay_keeps_the_score() {
+        let (score, updated_at) = config(0).decay(12_345, 0, 500 * PERIOD).unwrap();
+        assert_eq!(score, 12_345);
+        assert_eq!(updated_at, 500 * PERIOD);
+    }
+
+    #[test]
+    fn leftover_time_carries_into_the_next_period() {
+        let since = 1_000;
+        let now = since + 2 * PERIOD + PERIOD / 2;
+        let (score, updated_at) = config(500).decay(1_000, since, now).unwrap();
+        assert_eq!(score, 902);
+        assert_eq!(updated_at, since + 2 * PERIOD);
+
+        // Half a period later the carried-over time completes the third period
+        let (score, updated_at) = config(500).decay(score, updated_at, now).unwrap();
+        assert_eq!((score, updated_at), (902, since + 2 * PERIOD));
+        let (score, updated_at) = config(500).decay(score, updated_at, now + PERIOD / 2).unwrap();
+        assert_eq!(score, 856);
+        assert_eq!(updated_at, since + 3 * PERIOD);
+    }
+}
@@ -1,14 +1,138 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::PublishingPlatformError;
+use crate::state::access_policy::HoldingProof;
+use crate::state::content::{Book, Chapter};
+use crate::state::user_account::WriterAccount;
+
+pub const MAX_REVIEW_LEN: usize = 500;
+pub const MAX_REPLY_LEN: usize = 500;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum ReviewKind {
+    Chapter,
+    Book,
+    Writer,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Review {
     pub reviewer: Pubkey,
-    pub chapter: Pubkey,
+    pub target: Pubkey,          // Reviewed Chapter, Book or WriterAccount, depending on kind
+    pub kind: ReviewKind,
     pub book_collection: Pubkey,
-    #[max_len(500)]
+    #[max_len(MAX_REVIEW_LEN)]
     pub content: String,
     pub rating: u8,
     pub upvotes: u32,
     pub created_at: i64,
-}
\ No newline at end of file
+    pub bump: u8,
+    pub updated_at: Option<i64>, // Set once the review has been edited
+    pub weight: u64,             // Reviewer's rating weight when the review was submitted
+    pub flag_count: u32,
+    pub hidden: bool,            // Hidden by a moderator, no longer counted in rating aggregates
+    pub verified_holder: bool,   // Reviewer owned the chapter NFT or a paid unlock when reviewing
+    pub proof: HoldingProof,
+    // Part of the vote, flag and reply seeds, so a review deleted and submitted
+    // again at the same address does not inherit the old one's children
+    pub nonce: u64,
+}
+
+impl Review {
+    // Chapter reviews count towards the chapter and roll up into the book's chapter
+    // rating, book reviews only count towards the book's own rating
+    pub fn add_rating(&self, targets: RatingTargets) -> Result<()> {
+        match self.kind {
+            ReviewKind::Chapter => {
+                let chapter = targets.chapter.ok_or(PublishingPlatformError::ChapterAccountMissing)?;
+                let book = targets.book.ok_or(PublishingPlatformError::BookAccountMissing)?;
+                chapter.rating.add(self.rating, self.weight)?;
+                book.chapter_rating.add(self.rating, self.weight)
+            }
+            ReviewKind::Book => {
+                let book = targets.book.ok_or(PublishingPlatformError::BookAccountMissing)?;
+                book.book_rating.add(self.rating, self.weight)
+            }
+            ReviewKind::Writer => {
+                let writer = targets.writer.ok_or(PublishingPlatformError::WriterAccountNotFound)?;
+                writer.rating.add(self.rating, self.weight)
+            }
+        }
+    }
+
+    pub fn withdraw_rating(&self, targets: RatingTargets) -> Result<()> {
+        match self.kind {
+            ReviewKind::Chapter => {
+                let chapter = targets.chapter.ok_or(PublishingPlatformError::ChapterAccountMissing)?;
+                let book = targets.book.ok_or(PublishingPlatformError::BookAccountMissing)?;
+                chapter.rating.remove(self.rating, self.weight)?;
+                book.chapter_rating.remove(self.rating, self.weight)
+            }
+            ReviewKind::Book => {
+                let book = targets.book.ok_or(PublishingPlatformError::BookAccountMissing)?;
+                book.book_rating.remove(self.rating, self.weight)
+            }
+            ReviewKind::Writer => {
+                let writer = targets.writer.ok_or(PublishingPlatformError::WriterAccountNotFound)?;
+                writer.rating.remove(self.rating, self.weight)
+            }
+        }
+    }
+
+    pub fn validate(content: &str, rating: u8) -> Result<()> {
+        require!((1..=5).contains(&rating), PublishingPlatformError::InvalidRating);
+        require!(content.len() <= MAX_REVIEW_LEN, PublishingPlatformError::ReviewTooLong);
+        Ok(())
+    }
+}
+
+// Rating aggregates a review can count towards, only the ones its kind uses need to be set
+#[derive(Default)]
+pub struct RatingTargets<'a> {
+    pub chapter: Option<&'a mut Chapter>,
+    pub book: Option<&'a mut Book>,
+    pub writer: Option<&'a mut WriterAccount>,
+}
+
+// One per voter and review, so a reader can only upvote a review once
+#[account]
+#[derive(InitSpace)]
+pub struct ReviewVote {
+    pub voter: Pubkey,
+    pub review: Pubkey,
+    pub review_nonce: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+// One per reader and review, so a reader can only flag a review once
+#[account]
+#[derive(InitSpace)]
+pub struct ReviewFlag {
+    pub flagger: Pubkey,
+    pub review: Pubkey,
+    pub review_nonce: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+// The chapter author's answer to a review, one per review
+#[account]
+#[derive(InitSpace)]
+pub struct ReviewReply {
+    pub review: Pubkey,
+    pub review_nonce: u64,
+    pub author: Pubkey,
+    #[max_len(MAX_REPLY_LEN)]
+    pub content: String,
+    pub created_at: i64,
+    pub updated_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl ReviewReply {
+    pub fn validate(content: &str) -> Result<()> {
+        require!(content.len() <= MAX_REPLY_LEN, PublishingPlatformError::ReplyTooLong);
+        Ok(())
+    }
+}
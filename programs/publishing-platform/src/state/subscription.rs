@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PublishingPlatformError;
+
+pub const MAX_PLAN_BOOKS: usize = 10;
+
+#[account]
+#[derive(InitSpace)]
+pub struct SubscriptionPlan {
+    pub writer: Pubkey,
+    pub index: u32,
+    pub price_per_period: u64,      // Lamports charged for each period
+    pub period_seconds: i64,
+    #[max_len(MAX_PLAN_BOOKS)]
+    pub books: Vec<Pubkey>,         // Collection mints of the covered books
+    pub bump: u8,
+}
+
+impl SubscriptionPlan {
+    pub fn covers(&self, collection: &Pubkey) -> bool {
+        self.books.contains(collection)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Subscription {
+    pub reader: Pubkey,
+    pub plan: Pubkey,
+    pub started_at: i64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl Subscription {
+    // Checks that this subscription currently unlocks content by `author` in `collection`
+    pub fn ensure_grants(
+        &self,
+        reader: &Pubkey,
+        plan_key: &Pubkey,
+        plan: &SubscriptionPlan,
+        author: &Pubkey,
+        collection: &Pubkey,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            self.reader == *reader && self.plan == *plan_key,
+            PublishingPlatformError::InvalidSubscription
+        );
+        require!(
+            plan.writer == *author && plan.covers(collection),
+            PublishingPlatformError::SubscriptionNotCovering
+        );
+        require!(now < self.expires_at, PublishingPlatformError::SubscriptionExpired);
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_TIP_MESSAGE_LEN: usize = 100;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum TipTarget {
+    Writer,
+    Book(Pubkey),    // Book PDA
+    Chapter(Pubkey), // Chapter PDA
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TipRecord {
+    pub tipper: Pubkey,
+    pub writer: Pubkey,
+    pub target: TipTarget,
+    pub amount: u64,
+    #[max_len(MAX_TIP_MESSAGE_LEN)]
+    pub message: String,
+    pub index: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
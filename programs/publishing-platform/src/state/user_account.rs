@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::state::rating::RatingAggregate;
+use crate::state::reputation::ReputationConfig;
+
 #[account]
 #[derive(InitSpace)]
 pub struct WriterAccount {
@@ -7,6 +10,10 @@ pub struct WriterAccount {
     pub bump: u8,
     pub book_count: u32,
     pub total_royalties: u64,
+    pub plan_count: u32,
+    pub total_tips_received: u64,   // Lamports tipped through tip_writer
+    pub tip_count: u64,
+    pub rating: RatingAggregate,    // Reviews of the writer across all of their chapters
 }
 
 #[account]
@@ -16,7 +23,31 @@ pub struct ReaderAccount {
     pub bump: u8,
     pub review_count: u32,
     pub total_upvotes: u32,
-    pub reputation_score: u8,
+    pub reputation_score: u64,
+    pub reputation_updated_at: i64, // Last time decay was applied to reputation_score
+    pub penalty_count: u32,         // Reviews hidden or removed by moderators
+    pub reviews_submitted: u64,     // Never decremented, gives each review a fresh nonce
+}
+
+impl ReaderAccount {
+    pub fn apply_decay(&mut self, config: &ReputationConfig, now: i64) -> Result<()> {
+        let (score, updated_at) = config.decay(self.reputation_score, self.reputation_updated_at, now)?;
+        self.reputation_score = score;
+        self.reputation_updated_at = updated_at;
+        Ok(())
+    }
+
+    pub fn reward(&mut self, points: u64, config: &ReputationConfig, now: i64) -> Result<()> {
+        self.apply_decay(config, now)?;
+        self.reputation_score = self.reputation_score.saturating_add(points);
+        Ok(())
+    }
+
+    pub fn penalize(&mut self, points: u64, config: &ReputationConfig, now: i64) -> Result<()> {
+        self.apply_decay(config, now)?;
+        self.reputation_score = self.reputation_score.saturating_sub(points);
+        Ok(())
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
@@ -9,10 +9,17 @@ import {
   LAMPORTS_PER_SOL,
 } from "@solana/web3.js";
 import { assert } from "chai";
+import { createHash, randomBytes } from "crypto";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
+  burn,
+  createMint,
+  getAccount,
   getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
 } from "@solana/spl-token";
 
 const WRITER_ROLE = 1;
@@ -22,6 +29,12 @@ const TOKEN_METADATA_PROGRAM_ID = new anchor.web3.PublicKey(
   "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
 );
 
+const sha256 = (text: string): number[] =>
+  Array.from(createHash("sha256").update(text).digest());
+
+// Votes, flags and replies are keyed on the review's nonce as well as its address
+const nonceSeed = (nonce: anchor.BN): Buffer => nonce.toArrayLike(Buffer, "le", 8);
+
 const getMetadata = async (mint: PublicKey): Promise<PublicKey> => {
   return PublicKey.findProgramAddressSync(
     [
@@ -53,7 +66,15 @@ describe("publishing-platform", () => {
   const publishingPlatform = anchor.workspace
     .PublishingPlatform as Program<PublishingPlatform>;
 
-  const platformAccount = Keypair.generate();
+  const platformAccount = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform")],
+    publishingPlatform.programId
+  )[0];
+  const treasury = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury"), platformAccount.toBuffer()],
+    publishingPlatform.programId
+  )[0];
+  const platformFeeBps = 250;
   const user = provider.wallet;
 
   const reader = Keypair.generate();
@@ -91,6 +112,134 @@ describe("publishing-platform", () => {
     publishingPlatform.programId
   )[0];
 
+  const getExclusiveContentPDA = (index: number): PublicKey => {
+    const indexBuffer = Buffer.alloc(4);
+    indexBuffer.writeUInt32LE(index);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("exclusive"), bookMint.publicKey.toBuffer(), indexBuffer],
+      publishingPlatform.programId
+    )[0];
+  };
+
+  const minter = anchor.workspace.Minter as Program<Minter>;
+  const mintAuthority = PublicKey.findProgramAddressSync(
+    [Buffer.from("authority")],
+    minter.programId
+  )[0];
+
+  // Mints a collection NFT to the owner
+  const createCollectionNft = async (mint: Keypair, owner: Keypair) =>
+    minter.methods
+      .createCollection()
+      .accountsPartial({
+        user: owner.publicKey,
+        mint: mint.publicKey,
+        mintAuthority: mintAuthority,
+        metadata: await getMetadata(mint.publicKey),
+        masterEdition: await getMasterEdition(mint.publicKey),
+        destination: getAssociatedTokenAddressSync(
+          mint.publicKey,
+          owner.publicKey
+        ),
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      })
+      .signers([mint, owner])
+      .rpc({
+        skipPreflight: true,
+      });
+
+  // Mints a chapter NFT of the book's collection to the owner and, unless told
+  // otherwise, verifies it
+  const mintChapterNft = async (
+    mint: Keypair,
+    owner: Keypair,
+    verify = true
+  ) => {
+    const metadata = await getMetadata(mint.publicKey);
+    const tx = await minter.methods
+      .mintNft("ipfs_cid", "title", "symbol", 100)
+      .accountsPartial({
+        owner: owner.publicKey,
+        destination: getAssociatedTokenAddressSync(
+          mint.publicKey,
+          owner.publicKey
+        ),
+        metadata,
+        masterEdition: await getMasterEdition(mint.publicKey),
+        mint: mint.publicKey,
+        mintAuthority: mintAuthority,
+        collectionMint: collectionMint.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      })
+      .signers([mint, owner])
+      .rpc({
+        skipPreflight: true,
+      });
+    if (!verify) {
+      return tx;
+    }
+
+    await minter.methods
+      .verifyCollection()
+      .accountsPartial({
+        authority: owner.publicKey,
+        mintAuthority: mintAuthority,
+        mint: mint.publicKey,
+        metadata,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: await getMetadata(collectionMint.publicKey),
+        collectionMasterEdition: await getMasterEdition(
+          collectionMint.publicKey
+        ),
+        systemProgram: SystemProgram.programId,
+        sysvarInstruction: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      })
+      .signers([owner])
+      .rpc({
+        skipPreflight: true,
+      });
+    return tx;
+  };
+
+  // Mints a new chapter NFT to the owner and has the writer add it to the book
+  const addWriterChapter = async (
+    title: string,
+    content: object,
+    schedule: { earlyAccessAt: anchor.BN; releaseAt: anchor.BN } | null,
+    owner: Keypair = writer
+  ): Promise<PublicKey> => {
+    const mint = Keypair.generate();
+    await mintChapterNft(mint, owner);
+
+    const chapter = PublicKey.findProgramAddressSync(
+      [Buffer.from("chapter"), mint.publicKey.toBuffer()],
+      publishingPlatform.programId
+    )[0];
+    await publishingPlatform.methods
+      .addChapter(title, content as any, sha256(title), null, schedule)
+      .accountsPartial({
+        writer: writer.publicKey,
+        writerAccount,
+        chapterMint: mint.publicKey,
+        chapterMetadata: await getMetadata(mint.publicKey),
+        bookCollection: bookMint.publicKey,
+        book: bookPDA,
+        chapter,
+        metadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([writer])
+      .rpc();
+    return chapter;
+  };
+
   before(async () => {
     // Airdrop to tipper
     const airdropSignature = await provider.connection.requestAirdrop(
@@ -131,126 +280,113 @@ describe("publishing-platform", () => {
       ).lastValidBlockHeight,
     });
 
-    const minter = anchor.workspace.Minter as Program<Minter>;
     //Create Collection
-    const collectionMetadata = await getMetadata(collectionMint.publicKey);
-    const collectionMasterEdition = await getMasterEdition(
-      collectionMint.publicKey
-    );
-    const collectionDestination = getAssociatedTokenAddressSync(
-      collectionMint.publicKey,
-      reader.publicKey
-    );
-    const mintAuthority = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("authority")],
-      minter.programId
-    )[0];
-
+    // The writer holds the collection NFT so they can register the book
     try {
-      const tx = await minter.methods
-        .createCollection()
-        .accountsPartial({
-          user: reader.publicKey,
-          mint: collectionMint.publicKey,
-          mintAuthority: mintAuthority,
-          metadata: collectionMetadata,
-          masterEdition: collectionMasterEdition,
-          destination: collectionDestination,
-          systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
-        })
-        .signers([collectionMint, reader])
-        .rpc({
-          skipPreflight: true,
-        });
+      const tx = await createCollectionNft(collectionMint, writer);
       console.log("\nCollection NFT minted: TxID - ", tx);
     } catch (error) {
       console.error("Create Collection Error:", error);
       throw error;
     }
 
-    const nftMetadata = await getMetadata(nftMint.publicKey);
-    const nftMasterEdition = await getMasterEdition(nftMint.publicKey);
-    const nftDestination = getAssociatedTokenAddressSync(
-      nftMint.publicKey,
-      reader.publicKey
-    );
-
     //Mint NFT
     try {
-      const tx = await minter.methods
-        .mintNft("ipfs_cid", "title", "symbol", 100)
-        .accountsPartial({
-          owner: reader.publicKey,
-          destination: nftDestination,
-          metadata: nftMetadata,
-          masterEdition: nftMasterEdition,
-          mint: nftMint.publicKey,
-          mintAuthority: mintAuthority,
-          collectionMint: collectionMint.publicKey,
-          systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
-        })
-        .signers([nftMint, reader])
-        .rpc({
-          skipPreflight: true,
-        });
+      const tx = await mintChapterNft(nftMint, reader);
       console.log("\nNFT Minted! Your transaction signature", tx);
     } catch (error) {
       console.error("Mint NFT Error:", error);
       throw error;
     }
-
-    //Verify Collection
-    await minter.methods
-      .verifyCollection()
-      .accountsPartial({
-        authority: reader.publicKey,
-        mintAuthority: mintAuthority,
-        mint: nftMint.publicKey,
-        metadata: nftMetadata,
-        collectionMint: collectionMint.publicKey,
-        collectionMetadata: collectionMetadata,
-        collectionMasterEdition: collectionMasterEdition,
-        systemProgram: SystemProgram.programId,
-        sysvarInstruction: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
-        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
-      })
-      .signers([reader])
-      .rpc({
-        skipPreflight: true,
-      });
   });
 
   it("Publishing Platform initialized!", async () => {
     const tx = await publishingPlatform.methods
-      .initializePlatform()
+      .initializePlatform(platformFeeBps)
       .accountsPartial({
         user: user.publicKey,
-        platformAccount: platformAccount.publicKey,
+        platformAccount: platformAccount,
+        treasury: treasury,
         systemProgram: SystemProgram.programId,
       })
-      .signers([platformAccount])
       .rpc();
 
     console.log("Your transaction signature", tx);
 
     // Fetch the account and check its state
     const publishingPlatformAccount =
-      await publishingPlatform.account.platformAccount.fetch(
-        platformAccount.publicKey
-      );
+      await publishingPlatform.account.platformAccount.fetch(platformAccount);
+    assert.equal(
+      publishingPlatformAccount.admin.toBase58(),
+      user.publicKey.toBase58(),
+      "Initializer should be the platform admin"
+    );
+    assert.equal(publishingPlatformAccount.feeBps, platformFeeBps);
     assert.equal(
-      publishingPlatformAccount.counter,
-      0,
-      "Publishing platform count should be 0"
+      publishingPlatformAccount.treasury.toBase58(),
+      treasury.toBase58()
+    );
+    assert.equal(publishingPlatformAccount.paused, false);
+
+    // The treasury starts rent exempt so the first small fee can be paid into it
+    const rentExemptMinimum =
+      await provider.connection.getMinimumBalanceForRentExemption(0);
+    assert.isAtLeast(
+      await provider.connection.getBalance(treasury),
+      rentExemptMinimum
     );
   });
 
+  it("Admin can pause and unpause the platform", async () => {
+    await publishingPlatform.methods
+      .setPaused(true)
+      .accountsPartial({
+        admin: user.publicKey,
+        platformAccount: platformAccount,
+      })
+      .rpc();
+
+    try {
+      await publishingPlatform.methods
+        .createReaderAccount()
+        .accountsPartial({
+          user: reader.publicKey,
+          userAccount: readerAccount,
+          platformAccount: platformAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([reader])
+        .rpc();
+      assert.fail("Should not be able to use the platform while paused");
+    } catch (error) {
+      assert.include(error.message, "PlatformPaused");
+    }
+
+    await publishingPlatform.methods
+      .setPaused(false)
+      .accountsPartial({
+        admin: user.publicKey,
+        platformAccount: platformAccount,
+      })
+      .rpc();
+  });
+
+  it("Non-admin cannot update platform config", async () => {
+    try {
+      await publishingPlatform.methods
+        .updatePlatformConfig(0)
+        .accountsPartial({
+          admin: reader.publicKey,
+          platformAccount: platformAccount,
+        })
+        .signers([reader])
+        .rpc();
+      assert.fail("Only the admin should be able to update the config");
+    } catch (error) {
+      assert.include(error.message, "UnauthorizedAdmin");
+    }
+  });
+
   it("Create reader account", async () => {
     const tx = await publishingPlatform.methods
       .createReaderAccount()
@@ -309,12 +445,15 @@ describe("publishing-platform", () => {
       reader: tipper.publicKey,
       writer: writer.publicKey,
       writerAccount: writerAccount,
+      book: null,
+      chapter: null,
+      tipRecord: null,
       systemProgram: SystemProgram.programId,
     };
 
     const tipAmount = new anchor.BN(1000000); // 0.001 SOL
     const tx = await publishingPlatform.methods
-      .tipWriter(tipAmount)
+      .tipWriter(tipAmount, "")
       .accounts(tipAccounts)
       .rpc();
 
@@ -339,12 +478,15 @@ describe("publishing-platform", () => {
       reader: tipper.publicKey,
       writer: unregisteredWriter.publicKey,
       writerAccount: writerAccount,
+      book: null,
+      chapter: null,
+      tipRecord: null,
       systemProgram: SystemProgram.programId,
     };
 
     try {
       await publishingPlatform.methods
-        .tipWriter(new anchor.BN(1000000))
+        .tipWriter(new anchor.BN(1000000), "")
         .accounts(tipAccounts)
         .rpc();
       assert.fail("Should not be able to tip unregistered writer");
@@ -362,12 +504,15 @@ describe("publishing-platform", () => {
       reader: tipper.publicKey,
       writer: writer.publicKey,
       writerAccount: writerAccount,
+      book: null,
+      chapter: null,
+      tipRecord: null,
       systemProgram: SystemProgram.programId,
     };
 
     try {
       await publishingPlatform.methods
-        .tipWriter(new anchor.BN(0))
+        .tipWriter(new anchor.BN(0), "")
         .accounts(tipAccounts)
         .rpc();
       assert.fail("Should not be able to tip zero amount");
@@ -380,6 +525,145 @@ describe("publishing-platform", () => {
     }
   });
 
+  it("Can tip a writer with an allow-listed SPL token", async () => {
+    const payer = (provider.wallet as anchor.Wallet).payer;
+    const usdcMint = await createMint(
+      provider.connection,
+      payer,
+      payer.publicKey,
+      null,
+      6
+    );
+    const tipperAta = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      payer,
+      usdcMint,
+      tipper.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      payer,
+      usdcMint,
+      tipperAta.address,
+      payer,
+      10_000_000
+    );
+
+    const writerAta = getAssociatedTokenAddressSync(
+      usdcMint,
+      writer.publicKey
+    );
+    const tipAccounts = {
+      reader: tipper.publicKey,
+      writer: writer.publicKey,
+      writerAccount: writerAccount,
+      mint: usdcMint,
+      readerAta: tipperAta.address,
+      writerAta: writerAta,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+
+    try {
+      await publishingPlatform.methods
+        .tipWriterToken(new anchor.BN(1_000_000))
+        .accountsPartial(tipAccounts)
+        .rpc();
+      assert.fail("Mints must be allow-listed before they can be tipped");
+    } catch (error) {
+      assert.include(error.message, "TipMintNotAllowed");
+    }
+
+    await publishingPlatform.methods
+      .setAllowedTipMints([usdcMint])
+      .accountsPartial({
+        admin: user.publicKey,
+        platformAccount: platformAccount,
+      })
+      .rpc();
+
+    await publishingPlatform.methods
+      .tipWriterToken(new anchor.BN(1_000_000))
+      .accountsPartial(tipAccounts)
+      .rpc();
+
+    const writerTokenAccount = await getAccount(provider.connection, writerAta);
+    assert.equal(Number(writerTokenAccount.amount), 1_000_000);
+  });
+
+  it("Can tip a writer with an allow-listed Token-2022 mint", async () => {
+    const payer = (provider.wallet as anchor.Wallet).payer;
+    const token2022Mint = await createMint(
+      provider.connection,
+      payer,
+      payer.publicKey,
+      null,
+      6,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const tipperAta = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      payer,
+      token2022Mint,
+      tipper.publicKey,
+      false,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection,
+      payer,
+      token2022Mint,
+      tipperAta.address,
+      payer,
+      10_000_000,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    await publishingPlatform.methods
+      .setAllowedTipMints([token2022Mint])
+      .accountsPartial({
+        admin: user.publicKey,
+        platformAccount: platformAccount,
+      })
+      .rpc();
+
+    const writerAta = getAssociatedTokenAddressSync(
+      token2022Mint,
+      writer.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await publishingPlatform.methods
+      .tipWriterToken(new anchor.BN(2_500_000))
+      .accountsPartial({
+        reader: tipper.publicKey,
+        writer: writer.publicKey,
+        writerAccount: writerAccount,
+        mint: token2022Mint,
+        readerAta: tipperAta.address,
+        writerAta: writerAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const writerTokenAccount = await getAccount(
+      provider.connection,
+      writerAta,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert.equal(Number(writerTokenAccount.amount), 2_500_000);
+  });
+
   it("Create book and add chapters", async () => {
     // First create the book collection
 
@@ -387,23 +671,43 @@ describe("publishing-platform", () => {
       .createBook("My Book", 5, "Fiction")
       .accountsPartial({
         writer: writer.publicKey,
+        writerAccount: writerAccount,
         collectionMint: bookMint.publicKey,
+        collectionMetadata: await getMetadata(bookMint.publicKey),
+        collectionAta: getAssociatedTokenAddressSync(
+          bookMint.publicKey,
+          writer.publicKey
+        ),
         book: bookPDA,
+        metadataProgram: TOKEN_METADATA_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([writer])
       .rpc();
 
+    const writerAccountData =
+      await publishingPlatform.account.writerAccount.fetch(writerAccount);
+    assert.equal(writerAccountData.bookCount, 1);
+
     // Later, add a chapter to the existing book
 
     await publishingPlatform.methods
-      .addChapter("Chapter 1", "ipfs://content-uri")
+      .addChapter(
+        "Chapter 1",
+        { public: { contentUri: "ipfs://content-uri" } },
+        sha256("Chapter 1 text"),
+        null,
+        null
+      )
       .accountsPartial({
         writer: writer.publicKey,
+        writerAccount,
         chapterMint: chapterMint.publicKey,
+        chapterMetadata: await getMetadata(chapterMint.publicKey),
         bookCollection: bookMint.publicKey,
         book: bookPDA,
         chapter: chapterPDA,
+        metadataProgram: TOKEN_METADATA_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([writer])
@@ -417,26 +721,199 @@ describe("publishing-platform", () => {
       chapterPDA
     );
     assert.equal(chapterAccount.chapterNumber, 1);
+    // The book has no editors, so the chapter goes live right away
+    assert.deepEqual(chapterAccount.status, { published: {} });
     assert.equal(
       chapterAccount.bookCollection.equals(bookMint.publicKey),
       true
     );
   });
 
-  it("Create and access exclusive content", async () => {
-    const exclusiveContentPDA = PublicKey.findProgramAddressSync(
-      [Buffer.from("exclusive"), bookMint.publicKey.toBuffer()],
+  it("Books need a collection the writer holds", async () => {
+    const createBook = async (mint: PublicKey, collectionAta: PublicKey) =>
+      publishingPlatform.methods
+        .createBook("Not My Book", 5, "Fiction")
+        .accountsPartial({
+          writer: writer.publicKey,
+          writerAccount,
+          collectionMint: mint,
+          collectionMetadata: await getMetadata(mint),
+          collectionAta,
+          book: PublicKey.findProgramAddressSync(
+            [Buffer.from("book"), mint.toBuffer()],
+            publishingPlatform.programId
+          )[0],
+          metadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([writer])
+        .rpc();
+
+    // A collection held by someone else, with the writer not among its creators
+    const othersMint = Keypair.generate();
+    await createCollectionNft(othersMint, reader);
+    try {
+      await createBook(
+        othersMint.publicKey,
+        getAssociatedTokenAddressSync(othersMint.publicKey, reader.publicKey)
+      );
+      assert.fail("Expected a collection held by another wallet to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "NotCollectionOwner");
+    }
+    try {
+      await createBook(othersMint.publicKey, null);
+      assert.fail("Expected a missing collection token account to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "NotCollectionOwner");
+    }
+
+    // Chapter NFTs belong to a collection but are not one
+    try {
+      await createBook(chapterMint.publicKey, readerAta);
+      assert.fail("Expected a non-collection mint to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "NotACollection");
+    }
+  });
+
+  it("Chapters must be single verified NFTs of the book's collection", async () => {
+    const addChapter = async (mint: PublicKey) =>
+      publishingPlatform.methods
+        .addChapter(
+          "Stray Chapter",
+          { public: { contentUri: "ipfs://stray-chapter" } },
+          sha256("Stray Chapter"),
+          null,
+          null
+        )
+        .accountsPartial({
+          writer: writer.publicKey,
+          writerAccount,
+          chapterMint: mint,
+          chapterMetadata: await getMetadata(mint),
+          bookCollection: bookMint.publicKey,
+          book: bookPDA,
+          chapter: PublicKey.findProgramAddressSync(
+            [Buffer.from("chapter"), mint.toBuffer()],
+            publishingPlatform.programId
+          )[0],
+          metadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([writer])
+        .rpc();
+
+    // A collection NFT does not belong to any collection itself
+    const otherCollection = Keypair.generate();
+    await createCollectionNft(otherCollection, writer);
+    try {
+      await addChapter(otherCollection.publicKey);
+      assert.fail("Expected an NFT outside the book's collection to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "ChapterNotInCollection");
+    }
+
+    // Claiming the collection is not enough until its authority verifies it
+    const unverifiedMint = Keypair.generate();
+    await mintChapterNft(unverifiedMint, writer, false);
+    try {
+      await addChapter(unverifiedMint.publicKey);
+      assert.fail("Expected an unverified chapter NFT to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "CollectionNotVerified");
+    }
+
+    // Once the NFT is burned the mint no longer has a supply of exactly one
+    const burnedMint = Keypair.generate();
+    await mintChapterNft(burnedMint, writer);
+    await burn(
+      provider.connection,
+      writer,
+      getAssociatedTokenAddressSync(burnedMint.publicKey, writer.publicKey),
+      burnedMint.publicKey,
+      writer,
+      1
+    );
+    try {
+      await addChapter(burnedMint.publicKey);
+      assert.fail("Expected a mint without a supply of one to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidChapterSupply");
+    }
+
+    assert.equal(
+      (await publishingPlatform.account.book.fetch(bookPDA)).chapterCount,
+      1
+    );
+  });
+
+  it("Tip a writer for a chapter and keep a receipt", async () => {
+    const writerBefore = await publishingPlatform.account.writerAccount.fetch(
+      writerAccount
+    );
+    const tipIndex = Buffer.alloc(8);
+    tipIndex.writeBigUInt64LE(BigInt(writerBefore.tipCount.toString()));
+    const tipRecordPDA = PublicKey.findProgramAddressSync(
+      [Buffer.from("tip"), writer.publicKey.toBuffer(), tipIndex],
       publishingPlatform.programId
     )[0];
 
+    const tipAmount = new anchor.BN(2_000_000);
+    await publishingPlatform.methods
+      .tipWriter(tipAmount, "Loved the ending!")
+      .accountsPartial({
+        reader: tipper.publicKey,
+        writer: writer.publicKey,
+        writerAccount: writerAccount,
+        book: bookPDA,
+        chapter: chapterPDA,
+        tipRecord: tipRecordPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      // Book tips are paid out to every contributor of the book
+      .remainingAccounts([
+        { pubkey: writer.publicKey, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+
+    const tipRecord = await publishingPlatform.account.tipRecord.fetch(
+      tipRecordPDA
+    );
+    assert.equal(tipRecord.message, "Loved the ending!");
+    assert.equal(
+      tipRecord.target.chapter[0].toBase58(),
+      chapterPDA.toBase58()
+    );
+
+    const writerAfter = await publishingPlatform.account.writerAccount.fetch(
+      writerAccount
+    );
+    assert.equal(
+      writerAfter.tipCount.toNumber(),
+      writerBefore.tipCount.toNumber() + 1
+    );
+    assert.equal(
+      writerAfter.totalTipsReceived.sub(writerBefore.totalTipsReceived).toNumber(),
+      tipAmount.toNumber()
+    );
+
+    const bookAccount = await publishingPlatform.account.book.fetch(bookPDA);
+    assert.equal(bookAccount.totalTips.toNumber(), tipAmount.toNumber());
+  });
+
+  it("Create and access exclusive content", async () => {
+    const exclusiveContentPDA = getExclusiveContentPDA(0);
+
     // Author creates exclusive content
     const contentUri = "ipfs://exclusive-content-hash";
     try {
       const tx = await publishingPlatform.methods
-        .createExclusiveContent(contentUri)
+        .createExclusiveContent(contentUri, null, null)
         .accountsPartial({
           writer: writer.publicKey,
           collectionMint: bookMint.publicKey,
+          book: bookPDA,
           exclusiveContent: exclusiveContentPDA,
           systemProgram: SystemProgram.programId,
         })
@@ -458,6 +935,9 @@ describe("publishing-platform", () => {
           exclusiveContent: exclusiveContentPDA,
           chapterAta: readerAta,
           chapter: chapterPDA,
+          chapterUnlock: null,
+          subscription: null,
+          subscriptionPlan: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([reader])
@@ -469,52 +949,2225 @@ describe("publishing-platform", () => {
       console.error("Access Exclusive Content Error:", error);
       throw error;
     }
-  });
-
-  it("Submit review for chapter", async () => {
-    const review = "This is a great chapter! Really enjoyed the plot twists.";
-    const rating = 5;
 
-    const reviewPDA = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("review"),
-        reader.publicKey.toBuffer(),
-        chapterPDA.toBuffer(),
-      ],
+    // Someone else's token account must not grant access
+    try {
+      await publishingPlatform.methods
+        .verifyAccess()
+        .accountsPartial({
+          reader: writer.publicKey,
+          exclusiveContent: exclusiveContentPDA,
+          chapterAta: readerAta,
+          chapter: chapterPDA,
+          chapterUnlock: null,
+          subscription: null,
+          subscriptionPlan: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([writer])
+        .rpc();
+      assert.fail("Should not be able to borrow another reader's NFT");
+    } catch (error) {
+      assert.include(error.message, "InvalidTokenOwner");
+    }
+
+    // Reader records an access pass for the content gateway
+    const accessPassPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("access"),
+        reader.publicKey.toBuffer(),
+        exclusiveContentPDA.toBuffer(),
+      ],
       publishingPlatform.programId
     )[0];
 
+    await publishingPlatform.methods
+      .grantAccessPass()
+      .accountsPartial({
+        reader: reader.publicKey,
+        exclusiveContent: exclusiveContentPDA,
+        chapterAta: readerAta,
+        chapter: chapterPDA,
+        chapterUnlock: null,
+        subscription: null,
+        subscriptionPlan: null,
+        accessPass: accessPassPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reader])
+      .rpc();
+
+    const accessPass = await publishingPlatform.account.accessPass.fetch(
+      accessPassPDA
+    );
+    assert.equal(accessPass.reader.toBase58(), reader.publicKey.toBase58());
+    assert.equal(
+      accessPass.expiresAt.sub(accessPass.grantedAt).toNumber(),
+      24 * 60 * 60
+    );
+
+    const contentUriFromPass = await publishingPlatform.methods
+      .checkAccessPass()
+      .accountsPartial({
+        accessPass: accessPassPDA,
+        exclusiveContent: exclusiveContentPDA,
+      })
+      .view();
+    assert.equal(contentUriFromPass, contentUri);
+  });
+
+  it("Evaluate access policies over several holdings", async () => {
+    const exclusiveContentPDA = getExclusiveContentPDA(0);
+
+    const setPolicy = async (mode: object, predicates: object[]) =>
+      publishingPlatform.methods
+        .setAccessPolicy({ mode, predicates } as any)
+        .accountsPartial({
+          writer: writer.publicKey,
+          exclusiveContent: exclusiveContentPDA,
+        })
+        .signers([writer])
+        .rpc();
+
+    const verify = async () =>
+      publishingPlatform.methods
+        .verifyAccess()
+        .accountsPartial({
+          reader: reader.publicKey,
+          exclusiveContent: exclusiveContentPDA,
+          chapterAta: readerAta,
+          chapter: chapterPDA,
+          chapterUnlock: null,
+          subscription: null,
+          subscriptionPlan: null,
+          systemProgram: SystemProgram.programId,
+        })
+        // The book account lets the program check the full-book predicate
+        .remainingAccounts([
+          { pubkey: bookPDA, isSigner: false, isWritable: false },
+        ])
+        .signers([reader])
+        .rpc();
+
+    // The book only has one chapter, so the reader holds all of it
+    await setPolicy({ all: {} }, [
+      { holdsFullBook: { collection: bookMint.publicKey } },
+    ]);
+    await verify();
+
     try {
-      const tx = await publishingPlatform.methods
-        .submitReview(review, rating)
+      await setPolicy({ all: {} }, [
+        { holdsFullBook: { collection: bookMint.publicKey } },
+        {
+          holdsChapterNumber: {
+            collection: bookMint.publicKey,
+            chapterNumber: 2,
+          },
+        },
+      ]);
+      await verify();
+      assert.fail("Reader does not hold chapter 2");
+    } catch (error) {
+      assert.include(error.message, "ChapterNumberNotHeld");
+    }
+
+    // Either predicate is enough in "any" mode
+    await setPolicy({ any: {} }, [
+      {
+        holdsChapterNumber: {
+          collection: bookMint.publicKey,
+          chapterNumber: 2,
+        },
+      },
+      { holdsChapters: { collection: bookMint.publicKey, minCount: 1 } },
+    ]);
+    await verify();
+
+    // Restore the default policy for the remaining tests
+    await setPolicy({ all: {} }, [
+      { holdsChapters: { collection: bookMint.publicKey, minCount: 1 } },
+    ]);
+  });
+
+  it("Deactivate and close exclusive content", async () => {
+    const exclusiveContentPDA = getExclusiveContentPDA(1);
+    const availableUntil = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+    await publishingPlatform.methods
+      .createExclusiveContent(
+        "ipfs://second-exclusive-content",
+        availableUntil,
+        null
+      )
+      .accountsPartial({
+        writer: writer.publicKey,
+        collectionMint: bookMint.publicKey,
+        book: bookPDA,
+        exclusiveContent: exclusiveContentPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([writer])
+      .rpc();
+
+    let bookAccount = await publishingPlatform.account.book.fetch(bookPDA);
+    assert.equal(bookAccount.exclusiveContentCount, 2);
+    assert.equal(bookAccount.liveExclusiveContent, 2);
+
+    // The pass cannot outlive the content's availability window
+    const accessPassPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("access"),
+        reader.publicKey.toBuffer(),
+        exclusiveContentPDA.toBuffer(),
+      ],
+      publishingPlatform.programId
+    )[0];
+    await publishingPlatform.methods
+      .grantAccessPass()
+      .accountsPartial({
+        reader: reader.publicKey,
+        exclusiveContent: exclusiveContentPDA,
+        chapterAta: readerAta,
+        chapter: chapterPDA,
+        chapterUnlock: null,
+        subscription: null,
+        subscriptionPlan: null,
+        accessPass: accessPassPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reader])
+      .rpc();
+    const accessPass = await publishingPlatform.account.accessPass.fetch(
+      accessPassPDA
+    );
+    assert.equal(accessPass.expiresAt.toNumber(), availableUntil.toNumber());
+
+    const checkAccessPass = async () =>
+      publishingPlatform.methods
+        .checkAccessPass()
         .accountsPartial({
-          reviewer: reader.publicKey,
-          chapterAta: readerAta, // ATA of the chapter NFT
+          accessPass: accessPassPDA,
+          exclusiveContent: exclusiveContentPDA,
+        })
+        .view();
+    await checkAccessPass();
+
+    await publishingPlatform.methods
+      .deactivateExclusiveContent()
+      .accountsPartial({
+        writer: writer.publicKey,
+        exclusiveContent: exclusiveContentPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    try {
+      await publishingPlatform.methods
+        .verifyAccess()
+        .accountsPartial({
+          reader: reader.publicKey,
+          exclusiveContent: exclusiveContentPDA,
+          chapterAta: readerAta,
           chapter: chapterPDA,
-          review: reviewPDA,
+          chapterUnlock: null,
+          subscription: null,
+          subscriptionPlan: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([reader])
         .rpc();
+      assert.fail("Inactive content should not be accessible");
+    } catch (error) {
+      assert.include(error.message, "ExclusiveContentInactive");
+    }
 
-      console.log("Review submitted with signature:", tx);
+    // Passes granted earlier stop working with the content
+    try {
+      await checkAccessPass();
+      assert.fail("Passes for inactive content should not be valid");
+    } catch (error) {
+      assert.include(error.toString(), "ExclusiveContentInactive");
+    }
 
-      // Verify the review was created
-      const reviewAccount = await publishingPlatform.account.review.fetch(
-        reviewPDA
-      );
-      assert.equal(reviewAccount.content, review);
-      assert.equal(reviewAccount.rating, rating);
+    await publishingPlatform.methods
+      .closeExclusiveContent()
+      .accountsPartial({
+        writer: writer.publicKey,
+        exclusiveContent: exclusiveContentPDA,
+        book: bookPDA,
+      })
+      .signers([writer])
+      .rpc();
 
-      // Verify chapter rating was updated
-      const chapterAccount = await publishingPlatform.account.chapter.fetch(
-        chapterPDA
-      );
-      assert.equal(chapterAccount.reviewCount, 1);
-      assert.equal(chapterAccount.rating, rating);
+    const closed = await provider.connection.getAccountInfo(
+      exclusiveContentPDA
+    );
+    assert.isNull(closed);
+
+    try {
+      await checkAccessPass();
+      assert.fail("Passes for closed content should not be valid");
     } catch (error) {
-      console.error("Error submitting review:", error);
-      throw error;
+      assert.include(error.toString(), "AccountNotInitialized");
+    }
+
+    // The index keeps counting up, only the live count goes down
+    bookAccount = await publishingPlatform.account.book.fetch(bookPDA);
+    assert.equal(bookAccount.exclusiveContentCount, 2);
+    assert.equal(bookAccount.liveExclusiveContent, 1);
+  });
+
+  it("Subscribers can access content without holding an NFT", async () => {
+    const planIndex = Buffer.alloc(4);
+    planIndex.writeUInt32LE(0);
+    const planPDA = PublicKey.findProgramAddressSync(
+      [Buffer.from("plan"), writer.publicKey.toBuffer(), planIndex],
+      publishingPlatform.programId
+    )[0];
+    const subscriber = Keypair.generate();
+    const subscriptionPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("subscription"),
+        subscriber.publicKey.toBuffer(),
+        planPDA.toBuffer(),
+      ],
+      publishingPlatform.programId
+    )[0];
+
+    const airdropSignature = await provider.connection.requestAirdrop(
+      subscriber.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction({
+      signature: airdropSignature,
+      blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+      lastValidBlockHeight: (
+        await provider.connection.getLatestBlockhash()
+      ).lastValidBlockHeight,
+    });
+
+    const price = new anchor.BN(10_000_000);
+    await publishingPlatform.methods
+      .createSubscriptionPlan(price, new anchor.BN(30 * 24 * 60 * 60), [
+        bookMint.publicKey,
+      ])
+      .accountsPartial({
+        writer: writer.publicKey,
+        writerAccount: writerAccount,
+        subscriptionPlan: planPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([writer])
+      .rpc();
+
+    const writerInitialBalance = await provider.connection.getBalance(
+      writer.publicKey
+    );
+    const treasuryInitialBalance = await provider.connection.getBalance(
+      treasury
+    );
+
+    await publishingPlatform.methods
+      .subscribe(1)
+      .accountsPartial({
+        reader: subscriber.publicKey,
+        writer: writer.publicKey,
+        subscriptionPlan: planPDA,
+        subscription: subscriptionPDA,
+        treasury: treasury,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([subscriber])
+      .rpc();
+
+    const fee = price.toNumber() * platformFeeBps / 10_000;
+    assert.equal(
+      (await provider.connection.getBalance(writer.publicKey)) -
+        writerInitialBalance,
+      price.toNumber() - fee,
+      "Writer should receive the price minus the platform fee"
+    );
+    assert.equal(
+      (await provider.connection.getBalance(treasury)) - treasuryInitialBalance,
+      fee,
+      "Treasury should receive the platform fee"
+    );
+
+    await publishingPlatform.methods
+      .verifyAccess()
+      .accountsPartial({
+        reader: subscriber.publicKey,
+        exclusiveContent: getExclusiveContentPDA(0),
+        chapterAta: null,
+        chapter: null,
+        chapterUnlock: null,
+        subscription: subscriptionPDA,
+        subscriptionPlan: planPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([subscriber])
+      .rpc();
+
+    const before = await publishingPlatform.account.subscription.fetch(
+      subscriptionPDA
+    );
+    await publishingPlatform.methods
+      .renew(2)
+      .accountsPartial({
+        reader: subscriber.publicKey,
+        writer: writer.publicKey,
+        subscriptionPlan: planPDA,
+        subscription: subscriptionPDA,
+        treasury: treasury,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([subscriber])
+      .rpc();
+    const after = await publishingPlatform.account.subscription.fetch(
+      subscriptionPDA
+    );
+    assert.equal(
+      after.expiresAt.sub(before.expiresAt).toNumber(),
+      2 * 30 * 24 * 60 * 60,
+      "Renewing should extend the current expiry"
+    );
+  });
+
+  it("Unlock a chapter by paying its price", async () => {
+    const price = new anchor.BN(5_000_000);
+    const unlockPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("unlock"),
+        tipper.publicKey.toBuffer(),
+        chapterPDA.toBuffer(),
+      ],
+      publishingPlatform.programId
+    )[0];
+
+    await publishingPlatform.methods
+      .setChapterPrice(price)
+      .accountsPartial({
+        writer: writer.publicKey,
+        chapter: chapterPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    const writerInitialBalance = await provider.connection.getBalance(
+      writer.publicKey
+    );
+
+    await publishingPlatform.methods
+      .unlockChapter()
+      .accountsPartial({
+        reader: tipper.publicKey,
+        writerAccount: writerAccount,
+        chapter: chapterPDA,
+        book: bookPDA,
+        chapterUnlock: unlockPDA,
+        treasury: treasury,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        { pubkey: writer.publicKey, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+
+    const fee = (price.toNumber() * platformFeeBps) / 10_000;
+    assert.equal(
+      (await provider.connection.getBalance(writer.publicKey)) -
+        writerInitialBalance,
+      price.toNumber() - fee
+    );
+
+    const bookAccount = await publishingPlatform.account.book.fetch(bookPDA);
+    assert.equal(bookAccount.totalSales.toNumber(), price.toNumber());
+
+    const unlock = await publishingPlatform.account.chapterUnlock.fetch(
+      unlockPDA
+    );
+    assert.equal(unlock.pricePaid.toNumber(), price.toNumber());
+
+    // The receipt proves access in place of the chapter NFT
+    await publishingPlatform.methods
+      .verifyAccess()
+      .accountsPartial({
+        reader: tipper.publicKey,
+        exclusiveContent: getExclusiveContentPDA(0),
+        chapter: chapterPDA,
+        chapterAta: null,
+        chapterUnlock: unlockPDA,
+        subscription: null,
+        subscriptionPlan: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("Admin withdraws collected fees but leaves the treasury rent exempt", async () => {
+    const rentExemptMinimum =
+      await provider.connection.getMinimumBalanceForRentExemption(0);
+    const available =
+      (await provider.connection.getBalance(treasury)) - rentExemptMinimum;
+    assert.isAbove(available, 0);
+
+    try {
+      await publishingPlatform.methods
+        .withdrawTreasury(new anchor.BN(available + 1))
+        .accountsPartial({
+          admin: user.publicKey,
+          platformAccount,
+          treasury,
+          destination: user.publicKey,
+        })
+        .rpc();
+      assert.fail("Expected withdrawing the rent reserve to fail");
+    } catch (error) {
+      assert.include(error.toString(), "InsufficientTreasuryBalance");
     }
+
+    try {
+      await publishingPlatform.methods
+        .withdrawTreasury(new anchor.BN(available))
+        .accountsPartial({
+          admin: reader.publicKey,
+          platformAccount,
+          treasury,
+          destination: reader.publicKey,
+        })
+        .signers([reader])
+        .rpc();
+      assert.fail("Expected non-admin withdrawal to fail");
+    } catch (error) {
+      assert.include(error.toString(), "UnauthorizedAdmin");
+    }
+
+    // Pay out to a funded wallet that is not the fee payer, so the delta is exact
+    const destinationBefore = await provider.connection.getBalance(
+      writer.publicKey
+    );
+    await publishingPlatform.methods
+      .withdrawTreasury(new anchor.BN(available))
+      .accountsPartial({
+        admin: user.publicKey,
+        platformAccount,
+        treasury,
+        destination: writer.publicKey,
+      })
+      .rpc();
+
+    assert.equal(await provider.connection.getBalance(treasury), rentExemptMinimum);
+    assert.equal(
+      (await provider.connection.getBalance(writer.publicKey)) -
+        destinationBefore,
+      available
+    );
+  });
+
+  it("Submit review for chapter", async () => {
+    const review = "This is a great chapter! Really enjoyed the plot twists.";
+    const rating = 5;
+
+    const reviewPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("review"),
+        reader.publicKey.toBuffer(),
+        chapterPDA.toBuffer(),
+      ],
+      publishingPlatform.programId
+    )[0];
+
+    try {
+      const tx = await publishingPlatform.methods
+        .submitReview(review, rating)
+        .accountsPartial({
+          reviewer: reader.publicKey,
+          chapterAta: readerAta, // ATA of the chapter NFT
+          chapterUnlock: null,
+          subscription: null,
+          subscriptionPlan: null,
+          chapter: chapterPDA,
+          book: bookPDA,
+          review: reviewPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([reader])
+        .rpc();
+
+      console.log("Review submitted with signature:", tx);
+
+      // Verify the review was created
+      const reviewAccount = await publishingPlatform.account.review.fetch(
+        reviewPDA
+      );
+      assert.equal(reviewAccount.content, review);
+      assert.equal(reviewAccount.rating, rating);
+      assert.isTrue(reviewAccount.verifiedHolder);
+      assert.deepEqual(reviewAccount.proof, { chapterNft: {} });
+
+      // Verify chapter rating was updated
+      const chapterAccount = await publishingPlatform.account.chapter.fetch(
+        chapterPDA
+      );
+      assert.equal(chapterAccount.reviewCount, 1);
+      assert.equal(chapterAccount.rating.ratingCount, 1);
+      assert.equal(chapterAccount.rating.average, rating * 100);
+      // A brand-new reader is weighted at the default floor of 10
+      assert.equal(reviewAccount.weight.toNumber(), 10);
+      assert.equal(chapterAccount.rating.weightTotal.toNumber(), 10);
+      assert.equal(chapterAccount.rating.weightedAverage, rating * 100);
+
+      // Verify the book aggregate picked up the chapter review
+      const bookAccount = await publishingPlatform.account.book.fetch(bookPDA);
+      assert.equal(bookAccount.chapterRating.ratingCount, 1);
+      assert.equal(bookAccount.chapterRating.average, rating * 100);
+
+      // Default weights: 10 for the review plus 15 for proving access
+      const readerAccountData =
+        await publishingPlatform.account.readerAccount.fetch(readerAccount);
+      assert.equal(readerAccountData.reputationScore.toNumber(), 25);
+    } catch (error) {
+      console.error("Error submitting review:", error);
+      throw error;
+    }
+  });
+
+  it("Upvote a review once and remove the vote", async () => {
+    const reviewPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("review"),
+        reader.publicKey.toBuffer(),
+        chapterPDA.toBuffer(),
+      ],
+      publishingPlatform.programId
+    )[0];
+    const voterAccount = PublicKey.findProgramAddressSync(
+      [Buffer.from("reader"), user.publicKey.toBuffer()],
+      publishingPlatform.programId
+    )[0];
+    const reviewNonce = (await publishingPlatform.account.review.fetch(reviewPDA))
+      .nonce;
+    const votePDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("vote"),
+        user.publicKey.toBuffer(),
+        reviewPDA.toBuffer(),
+        nonceSeed(reviewNonce),
+      ],
+      publishingPlatform.programId
+    )[0];
+
+    await publishingPlatform.methods
+      .createReaderAccount()
+      .accountsPartial({
+        user: user.publicKey,
+        userAccount: voterAccount,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await publishingPlatform.methods
+      .upvoteReview()
+      .accountsPartial({
+        voter: user.publicKey,
+        voterAccount,
+        review: reviewPDA,
+        reviewerAccount: readerAccount,
+        reviewVote: votePDA,
+      })
+      .rpc();
+
+    let reviewAccount = await publishingPlatform.account.review.fetch(
+      reviewPDA
+    );
+    assert.equal(reviewAccount.upvotes, 1);
+    const reviewerData = await publishingPlatform.account.readerAccount.fetch(
+      readerAccount
+    );
+    assert.equal(reviewerData.totalUpvotes, 1);
+    assert.equal(reviewerData.reputationScore.toNumber(), 30);
+
+    // A second vote from the same reader hits the existing vote PDA
+    try {
+      await publishingPlatform.methods
+        .upvoteReview()
+        .accountsPartial({
+          voter: user.publicKey,
+          voterAccount,
+          review: reviewPDA,
+          reviewerAccount: readerAccount,
+          reviewVote: votePDA,
+        })
+        .rpc();
+      assert.fail("Expected duplicate vote to fail");
+    } catch (error) {
+      assert.include(error.toString(), "already in use");
+    }
+
+    // Reviewers cannot upvote their own review
+    try {
+      await publishingPlatform.methods
+        .upvoteReview()
+        .accountsPartial({
+          voter: reader.publicKey,
+          voterAccount: readerAccount,
+          review: reviewPDA,
+          reviewerAccount: readerAccount,
+          reviewVote: PublicKey.findProgramAddressSync(
+            [
+              Buffer.from("vote"),
+              reader.publicKey.toBuffer(),
+              reviewPDA.toBuffer(),
+              nonceSeed(reviewNonce),
+            ],
+            publishingPlatform.programId
+          )[0],
+        })
+        .signers([reader])
+        .rpc();
+      assert.fail("Expected a self vote to fail");
+    } catch (error) {
+      assert.include(error.toString(), "SelfVoteNotAllowed");
+    }
+    assert.equal(
+      (await publishingPlatform.account.review.fetch(reviewPDA)).upvotes,
+      1
+    );
+
+    await publishingPlatform.methods
+      .removeUpvote()
+      .accountsPartial({
+        voter: user.publicKey,
+        review: reviewPDA,
+        reviewerAccount: readerAccount,
+        reviewVote: votePDA,
+      })
+      .rpc();
+
+    reviewAccount = await publishingPlatform.account.review.fetch(reviewPDA);
+    assert.equal(reviewAccount.upvotes, 0);
+    assert.isNull(await provider.connection.getAccountInfo(votePDA));
+  });
+
+  it("Cannot review with someone else's chapter NFT", async () => {
+    const reviewPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("review"),
+        user.publicKey.toBuffer(),
+        chapterPDA.toBuffer(),
+      ],
+      publishingPlatform.programId
+    )[0];
+
+    try {
+      await publishingPlatform.methods
+        .submitReview("I never read this", 1)
+        .accountsPartial({
+          reviewer: user.publicKey,
+          chapterAta: readerAta, // Owned by the reader, not the reviewer
+          chapterUnlock: null,
+          subscription: null,
+          subscriptionPlan: null,
+          chapter: chapterPDA,
+          book: bookPDA,
+          review: reviewPDA,
+        })
+        .rpc();
+      assert.fail("Expected review with a foreign token account to fail");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidTokenOwner");
+    }
+  });
+
+  it("Writer replies to a review, edits and deletes the reply", async () => {
+    const reviewPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("review"),
+        reader.publicKey.toBuffer(),
+        chapterPDA.toBuffer(),
+      ],
+      publishingPlatform.programId
+    )[0];
+    const reviewNonce = (await publishingPlatform.account.review.fetch(reviewPDA))
+      .nonce;
+    const replyPDA = PublicKey.findProgramAddressSync(
+      [Buffer.from("reply"), reviewPDA.toBuffer(), nonceSeed(reviewNonce)],
+      publishingPlatform.programId
+    )[0];
+
+    try {
+      await publishingPlatform.methods
+        .replyToReview("I am not the author")
+        .accountsPartial({
+          writer: user.publicKey,
+          review: reviewPDA,
+          chapter: chapterPDA,
+          book: bookPDA,
+          reviewReply: replyPDA,
+          writerAccount: null,
+        })
+        .rpc();
+      assert.fail("Expected reply from a non-author to fail");
+    } catch (error) {
+      assert.include(error.toString(), "UnauthorizedWriter");
+    }
+
+    await publishingPlatform.methods
+      .replyToReview("Thanks for reading!")
+      .accountsPartial({
+        writer: writer.publicKey,
+        review: reviewPDA,
+        chapter: chapterPDA,
+        book: bookPDA,
+        reviewReply: replyPDA,
+        writerAccount: null,
+      })
+      .signers([writer])
+      .rpc();
+
+    let replyAccount = await publishingPlatform.account.reviewReply.fetch(
+      replyPDA
+    );
+    assert.equal(replyAccount.content, "Thanks for reading!");
+    assert.equal(replyAccount.review.toBase58(), reviewPDA.toBase58());
+
+    await publishingPlatform.methods
+      .editReviewReply("Thanks for reading, more twists coming!")
+      .accountsPartial({
+        writer: writer.publicKey,
+        reviewReply: replyPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    replyAccount = await publishingPlatform.account.reviewReply.fetch(replyPDA);
+    assert.equal(replyAccount.content, "Thanks for reading, more twists coming!");
+    assert.isNotNull(replyAccount.updatedAt);
+
+    await publishingPlatform.methods
+      .deleteReviewReply()
+      .accountsPartial({
+        writer: writer.publicKey,
+        reviewReply: replyPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    assert.isNull(await provider.connection.getAccountInfo(replyPDA));
+  });
+
+  it("Admin tunes reputation weights and anyone can run the decay crank", async () => {
+    const config = {
+      reviewWeight: 20,
+      verifiedReviewWeight: 30,
+      upvoteWeight: 10,
+      penaltyWeight: 200,
+      decayBps: 1_000,
+      decayPeriodSeconds: new anchor.BN(7 * 24 * 60 * 60),
+      ratingWeightFloor: new anchor.BN(20),
+      ratingWeightCap: new anchor.BN(1_000),
+    };
+
+    try {
+      await publishingPlatform.methods
+        .setReputationConfig({ ...config, decayBps: 20_000 })
+        .accountsPartial({
+          admin: user.publicKey,
+          platformAccount,
+        })
+        .rpc();
+      assert.fail("Expected invalid decay to fail");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidReputationConfig");
+    }
+
+    await publishingPlatform.methods
+      .setReputationConfig(config)
+      .accountsPartial({
+        admin: user.publicKey,
+        platformAccount,
+      })
+      .rpc();
+
+    const platformData = await publishingPlatform.account.platformAccount.fetch(
+      platformAccount
+    );
+    assert.equal(platformData.reputation.upvoteWeight, 10);
+    assert.equal(platformData.reputation.decayBps, 1_000);
+
+    // No full decay period has passed yet, so the score is unchanged
+    const before = await publishingPlatform.account.readerAccount.fetch(
+      readerAccount
+    );
+    await publishingPlatform.methods
+      .recomputeReputation()
+      .accountsPartial({
+        readerAccount,
+        platformAccount,
+      })
+      .rpc();
+    const after = await publishingPlatform.account.readerAccount.fetch(
+      readerAccount
+    );
+    assert.equal(
+      after.reputationScore.toNumber(),
+      before.reputationScore.toNumber()
+    );
+  });
+
+  it("Edit and delete a review", async () => {
+    const reviewPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("review"),
+        reader.publicKey.toBuffer(),
+        chapterPDA.toBuffer(),
+      ],
+      publishingPlatform.programId
+    )[0];
+
+    await publishingPlatform.methods
+      .editReview("Second read was less convincing.", 3)
+      .accountsPartial({
+        reviewer: reader.publicKey,
+        chapter: chapterPDA,
+        book: bookPDA,
+        review: reviewPDA,
+        writerAccount: null,
+      })
+      .signers([reader])
+      .rpc();
+
+    const reviewAccount = await publishingPlatform.account.review.fetch(
+      reviewPDA
+    );
+    assert.equal(reviewAccount.rating, 3);
+    assert.isNotNull(reviewAccount.updatedAt);
+
+    let chapterAccount = await publishingPlatform.account.chapter.fetch(
+      chapterPDA
+    );
+    assert.equal(chapterAccount.reviewCount, 1);
+    assert.equal(chapterAccount.rating.ratingSum.toNumber(), 3);
+    assert.equal(chapterAccount.rating.average, 300);
+    assert.equal(chapterAccount.rating.weightedAverage, 300);
+    assert.equal(
+      (await publishingPlatform.account.book.fetch(bookPDA)).chapterRating
+        .average,
+      300
+    );
+
+    await publishingPlatform.methods
+      .deleteReview()
+      .accountsPartial({
+        reviewer: reader.publicKey,
+        readerAccount,
+        chapter: chapterPDA,
+        book: bookPDA,
+        review: reviewPDA,
+        writerAccount: null,
+      })
+      .signers([reader])
+      .rpc();
+
+    assert.isNull(await provider.connection.getAccountInfo(reviewPDA));
+    chapterAccount = await publishingPlatform.account.chapter.fetch(chapterPDA);
+    assert.equal(chapterAccount.reviewCount, 0);
+    assert.equal(chapterAccount.rating.ratingCount, 0);
+    assert.equal(chapterAccount.rating.average, 0);
+    assert.equal(
+      (await publishingPlatform.account.readerAccount.fetch(readerAccount))
+        .reviewCount,
+      0
+    );
+  });
+
+  it("Votes on a deleted review do not carry over to its resubmission", async () => {
+    const reviewPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("review"),
+        reader.publicKey.toBuffer(),
+        chapterPDA.toBuffer(),
+      ],
+      publishingPlatform.programId
+    )[0];
+    const getVotePDA = async (): Promise<PublicKey> => {
+      const { nonce } = await publishingPlatform.account.review.fetch(reviewPDA);
+      return PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          user.publicKey.toBuffer(),
+          reviewPDA.toBuffer(),
+          nonceSeed(nonce),
+        ],
+        publishingPlatform.programId
+      )[0];
+    };
+    const submit = () =>
+      publishingPlatform.methods
+        .submitReview("Worth a second look", 4)
+        .accountsPartial({
+          reviewer: reader.publicKey,
+          chapterAta: readerAta,
+          chapterUnlock: null,
+          subscription: null,
+          subscriptionPlan: null,
+          chapter: chapterPDA,
+          book: bookPDA,
+          review: reviewPDA,
+        })
+        .signers([reader])
+        .rpc();
+    const deleteReview = () =>
+      publishingPlatform.methods
+        .deleteReview()
+        .accountsPartial({
+          reviewer: reader.publicKey,
+          readerAccount,
+          chapter: chapterPDA,
+          book: bookPDA,
+          review: reviewPDA,
+          writerAccount: null,
+        })
+        .signers([reader])
+        .rpc();
+    const upvote = (reviewVote: PublicKey) =>
+      publishingPlatform.methods
+        .upvoteReview()
+        .accountsPartial({
+          voter: user.publicKey,
+          review: reviewPDA,
+          reviewerAccount: readerAccount,
+          reviewVote,
+        })
+        .rpc();
+    const removeUpvote = (reviewVote: PublicKey) =>
+      publishingPlatform.methods
+        .removeUpvote()
+        .accountsPartial({
+          voter: user.publicKey,
+          review: reviewPDA,
+          reviewerAccount: readerAccount,
+          reviewVote,
+        })
+        .rpc();
+
+    await submit();
+    const staleVotePDA = await getVotePDA();
+    await upvote(staleVotePDA);
+
+    await deleteReview();
+    await submit();
+    const upvotesBefore = (
+      await publishingPlatform.account.readerAccount.fetch(readerAccount)
+    ).totalUpvotes;
+
+    // The old vote no longer matches the review, so closing it only refunds its
+    // rent and leaves the new review's counters alone
+    const voterBalanceBefore = await provider.connection.getBalance(
+      user.publicKey
+    );
+    await removeUpvote(staleVotePDA);
+    assert.isNull(await provider.connection.getAccountInfo(staleVotePDA));
+    assert.isAbove(
+      await provider.connection.getBalance(user.publicKey),
+      voterBalanceBefore
+    );
+    assert.equal(
+      (await publishingPlatform.account.readerAccount.fetch(readerAccount))
+        .totalUpvotes,
+      upvotesBefore
+    );
+    assert.equal(
+      (await publishingPlatform.account.review.fetch(reviewPDA)).upvotes,
+      0
+    );
+
+    // The same voter can vote on the new review
+    const freshVotePDA = await getVotePDA();
+    assert.notEqual(freshVotePDA.toBase58(), staleVotePDA.toBase58());
+    await upvote(freshVotePDA);
+    assert.equal(
+      (await publishingPlatform.account.review.fetch(reviewPDA)).upvotes,
+      1
+    );
+
+    await removeUpvote(freshVotePDA);
+    await deleteReview();
+  });
+
+  it("Moderators hide and remove flagged reviews", async () => {
+    const moderator = Keypair.generate();
+    const reviewPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("review"),
+        reader.publicKey.toBuffer(),
+        chapterPDA.toBuffer(),
+      ],
+      publishingPlatform.programId
+    )[0];
+
+    await publishingPlatform.methods
+      .submitReview("Spam spam spam", 1)
+      .accountsPartial({
+        reviewer: reader.publicKey,
+        chapterAta: readerAta,
+        chapterUnlock: null,
+        subscription: null,
+        subscriptionPlan: null,
+        chapter: chapterPDA,
+        book: bookPDA,
+        review: reviewPDA,
+      })
+      .signers([reader])
+      .rpc();
+
+    const reviewNonce = (await publishingPlatform.account.review.fetch(reviewPDA))
+      .nonce;
+    const flagPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("flag"),
+        user.publicKey.toBuffer(),
+        reviewPDA.toBuffer(),
+        nonceSeed(reviewNonce),
+      ],
+      publishingPlatform.programId
+    )[0];
+
+    await publishingPlatform.methods
+      .flagReview()
+      .accountsPartial({
+        flagger: user.publicKey,
+        review: reviewPDA,
+        reviewFlag: flagPDA,
+      })
+      .rpc();
+    assert.equal(
+      (await publishingPlatform.account.review.fetch(reviewPDA)).flagCount,
+      1
+    );
+
+    const unflag = () =>
+      publishingPlatform.methods
+        .unflagReview()
+        .accountsPartial({
+          flagger: user.publicKey,
+          review: reviewPDA,
+          reviewFlag: flagPDA,
+        })
+        .rpc();
+
+    // Flaggers can take their flag back while the review is up
+    await unflag();
+    assert.equal(
+      (await publishingPlatform.account.review.fetch(reviewPDA)).flagCount,
+      0
+    );
+    assert.isNull(await provider.connection.getAccountInfo(flagPDA));
+
+    await publishingPlatform.methods
+      .flagReview()
+      .accountsPartial({
+        flagger: user.publicKey,
+        review: reviewPDA,
+        reviewFlag: flagPDA,
+      })
+      .rpc();
+
+    try {
+      await publishingPlatform.methods
+        .hideReview()
+        .accountsPartial({
+          moderator: moderator.publicKey,
+          review: reviewPDA,
+          reviewerAccount: readerAccount,
+          chapter: chapterPDA,
+          book: bookPDA,
+          writerAccount: null,
+        })
+        .signers([moderator])
+        .rpc();
+      assert.fail("Expected non-moderator to be rejected");
+    } catch (error) {
+      assert.include(error.toString(), "NotAModerator");
+    }
+
+    await publishingPlatform.methods
+      .setModerators([moderator.publicKey])
+      .accountsPartial({
+        admin: user.publicKey,
+        platformAccount,
+      })
+      .rpc();
+
+    await publishingPlatform.methods
+      .hideReview()
+      .accountsPartial({
+        moderator: moderator.publicKey,
+        review: reviewPDA,
+        reviewerAccount: readerAccount,
+        chapter: chapterPDA,
+        book: bookPDA,
+        writerAccount: null,
+      })
+      .signers([moderator])
+      .rpc();
+
+    const reviewAccount = await publishingPlatform.account.review.fetch(
+      reviewPDA
+    );
+    assert.isTrue(reviewAccount.hidden);
+    const chapterAccount = await publishingPlatform.account.chapter.fetch(
+      chapterPDA
+    );
+    assert.equal(chapterAccount.reviewCount, 1);
+    assert.equal(chapterAccount.rating.ratingCount, 0);
+    assert.equal(
+      (await publishingPlatform.account.readerAccount.fetch(readerAccount))
+        .penaltyCount,
+      1
+    );
+
+    // The reviewer cannot shake off the hide by deleting and resubmitting
+    try {
+      await publishingPlatform.methods
+        .deleteReview()
+        .accountsPartial({
+          reviewer: reader.publicKey,
+          readerAccount,
+          chapter: chapterPDA,
+          book: bookPDA,
+          review: reviewPDA,
+          writerAccount: null,
+        })
+        .signers([reader])
+        .rpc();
+      assert.fail("Expected deleting a hidden review to fail");
+    } catch (error) {
+      assert.include(error.toString(), "ReviewHidden");
+    }
+
+    await publishingPlatform.methods
+      .removeReview()
+      .accountsPartial({
+        moderator: moderator.publicKey,
+        reviewer: reader.publicKey,
+        review: reviewPDA,
+        reviewerAccount: readerAccount,
+        chapter: chapterPDA,
+        book: bookPDA,
+        writerAccount: null,
+      })
+      .signers([moderator])
+      .rpc();
+
+    assert.isNull(await provider.connection.getAccountInfo(reviewPDA));
+    assert.equal(
+      (await publishingPlatform.account.chapter.fetch(chapterPDA)).reviewCount,
+      0
+    );
+
+    // Flags on the removed review can still be closed for their rent
+    await unflag();
+    assert.isNull(await provider.connection.getAccountInfo(flagPDA));
+  });
+
+  it("Review a whole book independently of its chapters", async () => {
+    const bookReviewPDA = PublicKey.findProgramAddressSync(
+      [Buffer.from("review"), reader.publicKey.toBuffer(), bookPDA.toBuffer()],
+      publishingPlatform.programId
+    )[0];
+    const chapterRatingBefore = (
+      await publishingPlatform.account.book.fetch(bookPDA)
+    ).chapterRating;
+
+    await publishingPlatform.methods
+      .submitBookReview("Loved the whole arc.", 4)
+      .accountsPartial({
+        reviewer: reader.publicKey,
+        book: bookPDA,
+        chapter: chapterPDA,
+        chapterAta: readerAta,
+        chapterUnlock: null,
+        review: bookReviewPDA,
+      })
+      .signers([reader])
+      .rpc();
+
+    const reviewAccount = await publishingPlatform.account.review.fetch(
+      bookReviewPDA
+    );
+    assert.deepEqual(reviewAccount.kind, { book: {} });
+    assert.equal(reviewAccount.target.toBase58(), bookPDA.toBase58());
+
+    let bookAccount = await publishingPlatform.account.book.fetch(bookPDA);
+    assert.equal(bookAccount.bookRating.ratingCount, 1);
+    assert.equal(bookAccount.bookRating.average, 400);
+    assert.equal(
+      bookAccount.chapterRating.ratingCount,
+      chapterRatingBefore.ratingCount
+    );
+
+    // Book reviews share the editing flow, without a chapter account
+    await publishingPlatform.methods
+      .editReview("Loved the whole arc, ending was perfect.", 5)
+      .accountsPartial({
+        reviewer: reader.publicKey,
+        chapter: null,
+        book: bookPDA,
+        review: bookReviewPDA,
+        writerAccount: null,
+      })
+      .signers([reader])
+      .rpc();
+
+    bookAccount = await publishingPlatform.account.book.fetch(bookPDA);
+    assert.equal(bookAccount.bookRating.average, 500);
+
+    await publishingPlatform.methods
+      .deleteReview()
+      .accountsPartial({
+        reviewer: reader.publicKey,
+        readerAccount,
+        chapter: null,
+        book: bookPDA,
+        review: bookReviewPDA,
+        writerAccount: null,
+      })
+      .signers([reader])
+      .rpc();
+
+    bookAccount = await publishingPlatform.account.book.fetch(bookPDA);
+    assert.equal(bookAccount.bookRating.ratingCount, 0);
+  });
+
+  it("Review a writer through any chapter they authored", async () => {
+    const writerReviewPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("review"),
+        reader.publicKey.toBuffer(),
+        writerAccount.toBuffer(),
+      ],
+      publishingPlatform.programId
+    )[0];
+
+    await publishingPlatform.methods
+      .submitWriterReview("Always worth following.", 4)
+      .accountsPartial({
+        reviewer: reader.publicKey,
+        writerAccount,
+        chapter: chapterPDA,
+        chapterAta: readerAta,
+        chapterUnlock: null,
+        review: writerReviewPDA,
+      })
+      .signers([reader])
+      .rpc();
+
+    const reviewAccount = await publishingPlatform.account.review.fetch(
+      writerReviewPDA
+    );
+    assert.deepEqual(reviewAccount.kind, { writer: {} });
+    assert.equal(reviewAccount.target.toBase58(), writerAccount.toBase58());
+
+    let writerData = await publishingPlatform.account.writerAccount.fetch(
+      writerAccount
+    );
+    assert.equal(writerData.rating.ratingCount, 1);
+    assert.equal(writerData.rating.average, 400);
+
+    // The writer replies and the review is edited and deleted through the
+    // shared flows, passing the writer account instead of a chapter or book
+    const replyPDA = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("reply"),
+        writerReviewPDA.toBuffer(),
+        nonceSeed(reviewAccount.nonce),
+      ],
+      publishingPlatform.programId
+    )[0];
+    await publishingPlatform.methods
+      .replyToReview("Thank you!")
+      .accountsPartial({
+        writer: writer.publicKey,
+        review: writerReviewPDA,
+        chapter: null,
+        book: null,
+        writerAccount,
+        reviewReply: replyPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    await publishingPlatform.methods
+      .editReview("Always worth following, never misses.", 5)
+      .accountsPartial({
+        reviewer: reader.publicKey,
+        chapter: null,
+        book: null,
+        writerAccount,
+        review: writerReviewPDA,
+      })
+      .signers([reader])
+      .rpc();
+
+    writerData = await publishingPlatform.account.writerAccount.fetch(
+      writerAccount
+    );
+    assert.equal(writerData.rating.average, 500);
+
+    await publishingPlatform.methods
+      .deleteReview()
+      .accountsPartial({
+        reviewer: reader.publicKey,
+        readerAccount,
+        chapter: null,
+        book: null,
+        writerAccount,
+        review: writerReviewPDA,
+      })
+      .signers([reader])
+      .rpc();
+
+    writerData = await publishingPlatform.account.writerAccount.fetch(
+      writerAccount
+    );
+    assert.equal(writerData.rating.ratingCount, 0);
+  });
+
+  it("Author updates book details and status but cannot close it with chapters", async () => {
+    try {
+      await publishingPlatform.methods
+        .updateBook("My Book", "Fiction", 255)
+        .accountsPartial({
+          writer: writer.publicKey,
+          book: bookPDA,
+        })
+        .signers([writer])
+        .rpc();
+      assert.fail("Expected royalty over 100 to fail");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidRoyaltyPercentage");
+    }
+
+    await publishingPlatform.methods
+      .updateBook("My Book (Revised)", "Mystery", 10)
+      .accountsPartial({
+        writer: writer.publicKey,
+        book: bookPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    await publishingPlatform.methods
+      .setBookStatus({ ongoing: {} })
+      .accountsPartial({
+        writer: writer.publicKey,
+        book: bookPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    const bookAccount = await publishingPlatform.account.book.fetch(bookPDA);
+    assert.equal(bookAccount.title, "My Book (Revised)");
+    assert.equal(bookAccount.genre, "Mystery");
+    assert.equal(bookAccount.royaltyPercentage, 10);
+    assert.deepEqual(bookAccount.status, { ongoing: {} });
+
+    try {
+      await publishingPlatform.methods
+        .closeBook()
+        .accountsPartial({
+          writer: writer.publicKey,
+          writerAccount,
+          book: bookPDA,
+        })
+        .signers([writer])
+        .rpc();
+      assert.fail("Expected closing a book with chapters to fail");
+    } catch (error) {
+      assert.include(error.toString(), "BookHasChapters");
+    }
+  });
+
+  it("Author closes a book that never got chapters", async () => {
+    const emptyBookMint = Keypair.generate();
+    await createCollectionNft(emptyBookMint, writer);
+    const emptyBookPDA = PublicKey.findProgramAddressSync(
+      [Buffer.from("book"), emptyBookMint.publicKey.toBuffer()],
+      publishingPlatform.programId
+    )[0];
+
+    await publishingPlatform.methods
+      .createBook("Abandoned Book", 5, "Fiction")
+      .accountsPartial({
+        writer: writer.publicKey,
+        writerAccount,
+        collectionMint: emptyBookMint.publicKey,
+        collectionMetadata: await getMetadata(emptyBookMint.publicKey),
+        collectionAta: getAssociatedTokenAddressSync(
+          emptyBookMint.publicKey,
+          writer.publicKey
+        ),
+        book: emptyBookPDA,
+        metadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([writer])
+      .rpc();
+    const bookCountBefore = (
+      await publishingPlatform.account.writerAccount.fetch(writerAccount)
+    ).bookCount;
+
+    await publishingPlatform.methods
+      .closeBook()
+      .accountsPartial({
+        writer: writer.publicKey,
+        writerAccount,
+        book: emptyBookPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    assert.isNull(await provider.connection.getAccountInfo(emptyBookPDA));
+    assert.equal(
+      (await publishingPlatform.account.writerAccount.fetch(writerAccount))
+        .bookCount,
+      bookCountBefore - 1
+    );
+  });
+
+  it("Revise a chapter and keep its previous version", async () => {
+    const revisionIndex = Buffer.alloc(4);
+    revisionIndex.writeUInt32LE(0);
+    const revisionPDA = PublicKey.findProgramAddressSync(
+      [Buffer.from("revision"), chapterPDA.toBuffer(), revisionIndex],
+      publishingPlatform.programId
+    )[0];
+
+    await publishingPlatform.methods
+      .reviseChapter("ipfs://content-uri-v2", sha256("Chapter 1 text, fixed"))
+      .accountsPartial({
+        writer: writer.publicKey,
+        chapter: chapterPDA,
+        book: bookPDA,
+        chapterRevision: revisionPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    const chapterAccount = await publishingPlatform.account.chapter.fetch(
+      chapterPDA
+    );
+    assert.equal(chapterAccount.contentUri, "ipfs://content-uri-v2");
+    assert.deepEqual(
+      chapterAccount.contentHash,
+      sha256("Chapter 1 text, fixed")
+    );
+    assert.equal(chapterAccount.revision, 1);
+    // Without editors the revision goes live right away
+    assert.deepEqual(chapterAccount.status, { published: {} });
+
+    const revisionAccount =
+      await publishingPlatform.account.chapterRevision.fetch(revisionPDA);
+    assert.equal(revisionAccount.revision, 0);
+    assert.equal(revisionAccount.contentUri, "ipfs://content-uri");
+    assert.deepEqual(revisionAccount.contentHash, sha256("Chapter 1 text"));
+  });
+
+  it("Released chapters are readable and cannot be rescheduled", async () => {
+    const contentUri = await publishingPlatform.methods
+      .readChapter()
+      .accountsPartial({
+        reader: user.publicKey,
+        chapter: chapterPDA,
+        heldChapter: null,
+        heldChapterAta: null,
+      })
+      .view();
+    assert.equal(contentUri, "ipfs://content-uri-v2");
+
+    const now = Math.floor(Date.now() / 1000);
+    try {
+      await publishingPlatform.methods
+        .setReleaseSchedule({
+          earlyAccessAt: new anchor.BN(now + 3600),
+          releaseAt: new anchor.BN(now + 7200),
+        })
+        .accountsPartial({
+          writer: writer.publicKey,
+          chapter: chapterPDA,
+        })
+        .signers([writer])
+        .rpc();
+      assert.fail("Expected rescheduling a released chapter to fail");
+    } catch (error) {
+      assert.include(error.toString(), "ChapterAlreadyReleased");
+    }
+
+    // Chapters published in the clear have nothing to reveal
+    try {
+      await publishingPlatform.methods
+        .revealChapter("ipfs://content-uri-v2", Array(32).fill(7))
+        .accountsPartial({
+          revealer: user.publicKey,
+          chapter: chapterPDA,
+        })
+        .rpc();
+      assert.fail("Expected revealing a public chapter to fail");
+    } catch (error) {
+      assert.include(error.toString(), "ChapterNotCommitted");
+    }
+  });
+
+  it("Scheduled chapters open to collection holders before everyone else", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const scheduledChapter = await addWriterChapter(
+      "Scheduled chapter",
+      { public: { contentUri: "ipfs://scheduled-content" } },
+      {
+        earlyAccessAt: new anchor.BN(now + 3600),
+        releaseAt: new anchor.BN(now + 7200),
+      }
+    );
+
+    const readAs = (
+      signer: Keypair | null,
+      heldChapter: PublicKey | null,
+      heldChapterAta: PublicKey | null
+    ) =>
+      publishingPlatform.methods
+        .readChapter()
+        .accountsPartial({
+          reader: signer ? signer.publicKey : user.publicKey,
+          chapter: scheduledChapter,
+          heldChapter,
+          heldChapterAta,
+        })
+        .signers(signer ? [signer] : [])
+        .view();
+
+    // Holding a chapter of the book does not help before early access opens
+    try {
+      await readAs(reader, chapterPDA, readerAta);
+      assert.fail("Expected reading before early access to fail");
+    } catch (error) {
+      assert.include(error.toString(), "ChapterNotReleased");
+    }
+
+    await publishingPlatform.methods
+      .setReleaseSchedule({
+        earlyAccessAt: new anchor.BN(now - 60),
+        releaseAt: new anchor.BN(now + 7200),
+      })
+      .accountsPartial({
+        writer: writer.publicKey,
+        chapter: scheduledChapter,
+      })
+      .signers([writer])
+      .rpc();
+
+    try {
+      await readAs(null, null, null);
+      assert.fail("Expected a non-holder to wait for the public release");
+    } catch (error) {
+      assert.include(error.toString(), "EarlyAccessHoldersOnly");
+    }
+
+    const contentUri = await readAs(reader, chapterPDA, readerAta);
+    assert.equal(contentUri, "ipfs://scheduled-content");
+  });
+
+  it("Committed chapters stay sealed until the author reveals them", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const contentUri = "ipfs://sealed-content";
+    const salt = Array.from(randomBytes(32));
+    const uriCommitment = Array.from(
+      createHash("sha256")
+        .update(contentUri)
+        .update(Buffer.from(salt))
+        .digest()
+    );
+    const committedChapter = await addWriterChapter(
+      "Committed chapter",
+      { committed: { uriCommitment } },
+      {
+        earlyAccessAt: new anchor.BN(now + 3600),
+        releaseAt: new anchor.BN(now + 7200),
+      }
+    );
+
+    // Not readable even by a collection holder until it is revealed
+    try {
+      await publishingPlatform.methods
+        .readChapter()
+        .accountsPartial({
+          reader: reader.publicKey,
+          chapter: committedChapter,
+          heldChapter: chapterPDA,
+          heldChapterAta: readerAta,
+        })
+        .signers([reader])
+        .view();
+      assert.fail("Expected reading a sealed chapter to fail");
+    } catch (error) {
+      assert.include(error.toString(), "ChapterNotRevealed");
+    }
+
+    const reveal = (revealer: Keypair | null, salt: number[]) =>
+      publishingPlatform.methods
+        .revealChapter(contentUri, salt)
+        .accountsPartial({
+          revealer: revealer ? revealer.publicKey : user.publicKey,
+          chapter: committedChapter,
+        })
+        .signers(revealer ? [revealer] : [])
+        .rpc();
+
+    // Only the author can reveal before the release, even with the right preimage
+    try {
+      await reveal(null, salt);
+      assert.fail("Expected an early reveal by a non-author to fail");
+    } catch (error) {
+      assert.include(error.toString(), "RevealTooEarly");
+    }
+
+    try {
+      await reveal(writer, Array(32).fill(7));
+      assert.fail("Expected a reveal with the wrong salt to fail");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidReveal");
+    }
+
+    await reveal(writer, salt);
+
+    const chapterAccount = await publishingPlatform.account.chapter.fetch(
+      committedChapter
+    );
+    assert.equal(chapterAccount.contentUri, contentUri);
+    assert.isNotNull(chapterAccount.revealedAt);
+  });
+
+  it("Only book editors can rule on chapters in review", async () => {
+    const editor = Keypair.generate();
+
+    await publishingPlatform.methods
+      .setBookEditors([editor.publicKey])
+      .accountsPartial({
+        writer: writer.publicKey,
+        book: bookPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    const bookAccount = await publishingPlatform.account.book.fetch(bookPDA);
+    assert.equal(bookAccount.editors.length, 1);
+
+    // Published chapters cannot go back through review
+    try {
+      await publishingPlatform.methods
+        .submitForReview()
+        .accountsPartial({
+          writer: writer.publicKey,
+          chapter: chapterPDA,
+          book: bookPDA,
+        })
+        .signers([writer])
+        .rpc();
+      assert.fail("Expected resubmitting a published chapter to fail");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidChapterStatus");
+    }
+
+    try {
+      await publishingPlatform.methods
+        .approveChapter("Looks good to me")
+        .accountsPartial({
+          editor: writer.publicKey,
+          chapter: chapterPDA,
+          book: bookPDA,
+        })
+        .signers([writer])
+        .rpc();
+      assert.fail("Expected approval from a non-editor to fail");
+    } catch (error) {
+      assert.include(error.toString(), "NotAnEditor");
+    }
+
+    // Writers of the book cannot approve their own chapters
+    try {
+      await publishingPlatform.methods
+        .setBookEditors([editor.publicKey, writer.publicKey])
+        .accountsPartial({
+          writer: writer.publicKey,
+          book: bookPDA,
+        })
+        .signers([writer])
+        .rpc();
+      assert.fail("Expected adding the author as an editor to fail");
+    } catch (error) {
+      assert.include(error.toString(), "EditorCannotWrite");
+    }
+
+    // Editors only leave with their own signature
+    try {
+      await publishingPlatform.methods
+        .setBookEditors([])
+        .accountsPartial({
+          writer: writer.publicKey,
+          book: bookPDA,
+        })
+        .signers([writer])
+        .rpc();
+      assert.fail("Expected removing an editor without their signature to fail");
+    } catch (error) {
+      assert.include(error.toString(), "EditorRemovalNotSigned");
+    }
+
+    await publishingPlatform.methods
+      .setBookEditors([])
+      .accountsPartial({
+        writer: writer.publicKey,
+        book: bookPDA,
+      })
+      .remainingAccounts([
+        { pubkey: editor.publicKey, isSigner: true, isWritable: false },
+      ])
+      .signers([writer, editor])
+      .rpc();
+  });
+
+  it("Chapters of a book with editors go live only once approved", async () => {
+    const editor = Keypair.generate();
+    const workflowAccounts = (chapter: PublicKey) => ({
+      writer: writer.publicKey,
+      chapter,
+      book: bookPDA,
+    });
+
+    await publishingPlatform.methods
+      .setBookEditors([editor.publicKey])
+      .accountsPartial({
+        writer: writer.publicKey,
+        book: bookPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    // The reader holds the new chapter's NFT, but it starts as a draft
+    const draftChapter = await addWriterChapter(
+      "Edited chapter",
+      { public: { contentUri: "ipfs://edited-content" } },
+      null,
+      reader
+    );
+    let chapterAccount = await publishingPlatform.account.chapter.fetch(
+      draftChapter
+    );
+    assert.deepEqual(chapterAccount.status, { draft: {} });
+    const draftAta = getAssociatedTokenAddressSync(
+      chapterAccount.chapterMint,
+      reader.publicKey
+    );
+
+    const verifyAccess = () =>
+      publishingPlatform.methods
+        .verifyAccess()
+        .accountsPartial({
+          reader: reader.publicKey,
+          exclusiveContent: getExclusiveContentPDA(0),
+          chapterAta: draftAta,
+          chapter: draftChapter,
+          chapterUnlock: null,
+          subscription: null,
+          subscriptionPlan: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([reader])
+        .rpc();
+
+    try {
+      await verifyAccess();
+      assert.fail("Expected a draft chapter not to grant access");
+    } catch (error) {
+      assert.include(error.toString(), "ChapterNotPublished");
+    }
+
+    try {
+      await publishingPlatform.methods
+        .submitReview("Too early to say", 3)
+        .accountsPartial({
+          reviewer: reader.publicKey,
+          chapterAta: draftAta,
+          chapterUnlock: null,
+          subscription: null,
+          subscriptionPlan: null,
+          chapter: draftChapter,
+          book: bookPDA,
+          review: PublicKey.findProgramAddressSync(
+            [
+              Buffer.from("review"),
+              reader.publicKey.toBuffer(),
+              draftChapter.toBuffer(),
+            ],
+            publishingPlatform.programId
+          )[0],
+        })
+        .signers([reader])
+        .rpc();
+      assert.fail("Expected reviewing a draft chapter to fail");
+    } catch (error) {
+      assert.include(error.toString(), "ChapterNotPublished");
+    }
+
+    // Drafts cannot skip review while the book has editors
+    try {
+      await publishingPlatform.methods
+        .publishChapter()
+        .accountsPartial(workflowAccounts(draftChapter))
+        .signers([writer])
+        .rpc();
+      assert.fail("Expected publishing an unapproved chapter to fail");
+    } catch (error) {
+      assert.include(error.toString(), "InvalidChapterStatus");
+    }
+
+    await publishingPlatform.methods
+      .submitForReview()
+      .accountsPartial(workflowAccounts(draftChapter))
+      .signers([writer])
+      .rpc();
+    await publishingPlatform.methods
+      .approveChapter("Ready to go")
+      .accountsPartial({
+        editor: editor.publicKey,
+        chapter: draftChapter,
+        book: bookPDA,
+      })
+      .signers([editor])
+      .rpc();
+    await publishingPlatform.methods
+      .publishChapter()
+      .accountsPartial(workflowAccounts(draftChapter))
+      .signers([writer])
+      .rpc();
+
+    chapterAccount = await publishingPlatform.account.chapter.fetch(
+      draftChapter
+    );
+    assert.deepEqual(chapterAccount.status, { published: {} });
+    assert.equal(
+      chapterAccount.reviewedBy.toBase58(),
+      editor.publicKey.toBase58()
+    );
+
+    await verifyAccess();
+    const contentUri = await publishingPlatform.methods
+      .readChapter()
+      .accountsPartial({
+        reader: reader.publicKey,
+        chapter: draftChapter,
+        heldChapter: null,
+        heldChapterAta: null,
+      })
+      .signers([reader])
+      .view();
+    assert.equal(contentUri, "ipfs://edited-content");
+
+    // A revision needs fresh approval
+    const revisionIndex = Buffer.alloc(4);
+    revisionIndex.writeUInt32LE(0);
+    await publishingPlatform.methods
+      .reviseChapter("ipfs://edited-content-v2", sha256("Edited chapter v2"))
+      .accountsPartial({
+        ...workflowAccounts(draftChapter),
+        chapterRevision: PublicKey.findProgramAddressSync(
+          [Buffer.from("revision"), draftChapter.toBuffer(), revisionIndex],
+          publishingPlatform.programId
+        )[0],
+      })
+      .signers([writer])
+      .rpc();
+    chapterAccount = await publishingPlatform.account.chapter.fetch(
+      draftChapter
+    );
+    assert.deepEqual(chapterAccount.status, { draft: {} });
+    assert.isNull(chapterAccount.reviewedBy);
+
+    // Once the editor leaves, a chapter stuck in review is withdrawn and published directly
+    await publishingPlatform.methods
+      .submitForReview()
+      .accountsPartial(workflowAccounts(draftChapter))
+      .signers([writer])
+      .rpc();
+    await publishingPlatform.methods
+      .setBookEditors([])
+      .accountsPartial({
+        writer: writer.publicKey,
+        book: bookPDA,
+      })
+      .remainingAccounts([
+        { pubkey: editor.publicKey, isSigner: true, isWritable: false },
+      ])
+      .signers([writer, editor])
+      .rpc();
+    await publishingPlatform.methods
+      .withdrawChapter()
+      .accountsPartial(workflowAccounts(draftChapter))
+      .signers([writer])
+      .rpc();
+    await publishingPlatform.methods
+      .publishChapter()
+      .accountsPartial(workflowAccounts(draftChapter))
+      .signers([writer])
+      .rpc();
+
+    chapterAccount = await publishingPlatform.account.chapter.fetch(
+      draftChapter
+    );
+    assert.deepEqual(chapterAccount.status, { published: {} });
+  });
+
+  it("Authors cannot approve their own chapters", async () => {
+    const editor = Keypair.generate();
+    const setContributors = (canWrite: boolean) =>
+      publishingPlatform.methods
+        .setBookContributors([
+          {
+            wallet: writer.publicKey,
+            role: { author: {} },
+            shareBps: 10_000,
+            canWrite,
+          },
+        ])
+        .accountsPartial({
+          writer: writer.publicKey,
+          book: bookPDA,
+        })
+        .signers([writer])
+        .rpc();
+
+    await publishingPlatform.methods
+      .setBookEditors([editor.publicKey])
+      .accountsPartial({
+        writer: writer.publicKey,
+        book: bookPDA,
+      })
+      .signers([writer])
+      .rpc();
+    const chapter = await addWriterChapter(
+      "Self-approved chapter",
+      { public: { contentUri: "ipfs://self-approved" } },
+      null
+    );
+    await publishingPlatform.methods
+      .submitForReview()
+      .accountsPartial({
+        writer: writer.publicKey,
+        chapter,
+        book: bookPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    // Giving up write access lets the author join the editors...
+    await setContributors(false);
+    await publishingPlatform.methods
+      .setBookEditors([editor.publicKey, writer.publicKey])
+      .accountsPartial({
+        writer: writer.publicKey,
+        book: bookPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    // ...but not take write access back while still an editor
+    try {
+      await setContributors(true);
+      assert.fail("Expected an editor regaining write access to fail");
+    } catch (error) {
+      assert.include(error.toString(), "EditorCannotWrite");
+    }
+
+    // and never rule on a chapter they wrote
+    try {
+      await publishingPlatform.methods
+        .approveChapter("Approving myself")
+        .accountsPartial({
+          editor: writer.publicKey,
+          chapter,
+          book: bookPDA,
+        })
+        .signers([writer])
+        .rpc();
+      assert.fail("Expected the author approving their own chapter to fail");
+    } catch (error) {
+      assert.include(error.toString(), "EditorCannotWrite");
+    }
+
+    await publishingPlatform.methods
+      .setBookEditors([])
+      .accountsPartial({
+        writer: writer.publicKey,
+        book: bookPDA,
+      })
+      .remainingAccounts([
+        { pubkey: editor.publicKey, isSigner: true, isWritable: false },
+        { pubkey: writer.publicKey, isSigner: true, isWritable: false },
+      ])
+      .signers([writer, editor])
+      .rpc();
+    await setContributors(true);
+  });
+
+  it("Split book revenue between co-authors", async () => {
+    const coAuthor = Keypair.generate();
+    const coAuthorAccount = PublicKey.findProgramAddressSync(
+      [Buffer.from("writer"), coAuthor.publicKey.toBuffer()],
+      publishingPlatform.programId
+    )[0];
+
+    const airdropSignature = await provider.connection.requestAirdrop(
+      coAuthor.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction({
+      signature: airdropSignature,
+      blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+      lastValidBlockHeight: (
+        await provider.connection.getLatestBlockhash()
+      ).lastValidBlockHeight,
+    });
+    await publishingPlatform.methods
+      .createWriterAccount()
+      .accountsPartial({
+        user: coAuthor.publicKey,
+        userAccount: coAuthorAccount,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([coAuthor])
+      .rpc();
+
+    await publishingPlatform.methods
+      .setBookContributors([
+        {
+          wallet: writer.publicKey,
+          role: { author: {} },
+          shareBps: 7_000,
+          canWrite: true,
+        },
+        {
+          wallet: coAuthor.publicKey,
+          role: { coAuthor: {} },
+          shareBps: 3_000,
+          canWrite: true,
+        },
+      ])
+      .accountsPartial({
+        writer: writer.publicKey,
+        book: bookPDA,
+      })
+      .signers([writer])
+      .rpc();
+
+    const writerInitialBalance = await provider.connection.getBalance(
+      writer.publicKey
+    );
+    const coAuthorInitialBalance = await provider.connection.getBalance(
+      coAuthor.publicKey
+    );
+    const writerStatsBefore =
+      await publishingPlatform.account.writerAccount.fetch(writerAccount);
+
+    const tipAmount = 10_000_000;
+    await publishingPlatform.methods
+      .tipWriter(new anchor.BN(tipAmount), "For both of you")
+      .accountsPartial({
+        reader: tipper.publicKey,
+        writer: writer.publicKey,
+        writerAccount: writerAccount,
+        book: bookPDA,
+        chapter: null,
+        tipRecord: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        { pubkey: writer.publicKey, isSigner: false, isWritable: true },
+        { pubkey: coAuthor.publicKey, isSigner: false, isWritable: true },
+        { pubkey: coAuthorAccount, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+
+    assert.equal(
+      (await provider.connection.getBalance(writer.publicKey)) -
+        writerInitialBalance,
+      tipAmount * 0.7
+    );
+    assert.equal(
+      (await provider.connection.getBalance(coAuthor.publicKey)) -
+        coAuthorInitialBalance,
+      tipAmount * 0.3
+    );
+
+    // Each contributor's stats only count their own share
+    let writerStats = await publishingPlatform.account.writerAccount.fetch(
+      writerAccount
+    );
+    let coAuthorStats = await publishingPlatform.account.writerAccount.fetch(
+      coAuthorAccount
+    );
+    assert.equal(
+      writerStats.totalTipsReceived
+        .sub(writerStatsBefore.totalTipsReceived)
+        .toNumber(),
+      tipAmount * 0.7
+    );
+    assert.equal(coAuthorStats.totalTipsReceived.toNumber(), tipAmount * 0.3);
+
+    // Unlock royalties are credited the same way
+    const price = (await publishingPlatform.account.chapter.fetch(chapterPDA))
+      .priceLamports;
+    await publishingPlatform.methods
+      .unlockChapter()
+      .accountsPartial({
+        reader: reader.publicKey,
+        writerAccount: writerAccount,
+        chapter: chapterPDA,
+        book: bookPDA,
+        chapterUnlock: PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("unlock"),
+            reader.publicKey.toBuffer(),
+            chapterPDA.toBuffer(),
+          ],
+          publishingPlatform.programId
+        )[0],
+        treasury: treasury,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        { pubkey: writer.publicKey, isSigner: false, isWritable: true },
+        { pubkey: coAuthor.publicKey, isSigner: false, isWritable: true },
+        { pubkey: coAuthorAccount, isSigner: false, isWritable: true },
+      ])
+      .signers([reader])
+      .rpc();
+
+    const net = price.toNumber() - (price.toNumber() * platformFeeBps) / 10_000;
+    writerStats = await publishingPlatform.account.writerAccount.fetch(
+      writerAccount
+    );
+    coAuthorStats = await publishingPlatform.account.writerAccount.fetch(
+      coAuthorAccount
+    );
+    assert.equal(
+      writerStats.totalRoyalties
+        .sub(writerStatsBefore.totalRoyalties)
+        .toNumber(),
+      (net * 7_000) / 10_000
+    );
+    assert.equal(
+      coAuthorStats.totalRoyalties.toNumber(),
+      (net * 3_000) / 10_000
+    );
   });
 });